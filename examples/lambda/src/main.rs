@@ -0,0 +1,40 @@
+use lambda_http::{run, service_fn, Error, Request};
+
+// Import the prelude module to get all the necessary imports
+use octoapp::prelude::*;
+
+/// The handler for GitHub events
+///
+/// Called once the signature is verified and the payload parsed, an error
+/// responds with `500` so GitHub marks the delivery as failed.
+async fn webhook(event: WebHook<Event>) -> Result<(), OctoAppError> {
+    // Ignore events triggered by bots (including this app) to prevent loops
+    if event.is_bot() {
+        tracing::debug!("Ignoring event from bot: {:?}", event.sender());
+        return Ok(());
+    }
+
+    match event.into_inner() {
+        Event::Issues(issues) => {
+            tracing::info!("Received an issue event: {:?}", issues.issue.id);
+        }
+        event => {
+            tracing::info!("Received an event: {}", event.kind());
+        }
+    }
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    // Load the configuration (from the Lambda environment variables)
+    let config = OctoAppConfig::init().build()?;
+
+    run(service_fn(|request: Request| {
+        let config = config.clone();
+        async move {
+            Ok::<_, Error>(octoapp::ghlambda::handle_lambda_event(&config, request, webhook).await)
+        }
+    }))
+    .await
+}
@@ -5,7 +5,7 @@ use anyhow::Result;
 use rocket::State;
 
 // Import the prelude module to get all the necessary imports
-use octoapp::{ghrocket::OctoAppResult, prelude::*};
+use octoapp::{comments::upsert_comment, ghrocket::OctoAppResult, prelude::*};
 
 /// The webhook route for GitHub events
 ///
@@ -15,26 +15,43 @@ use octoapp::{ghrocket::OctoAppResult, prelude::*};
 /// You can either use `WebHook<Event>` to get all events, or use a specific
 /// event type like `WebHook<PingEvent>` to only get ping events.
 #[post("/", data = "<event>")]
-async fn webhook(state: &State<OctoAppState>, event: WebHook<Event>) -> OctoAppResult<()> {
+async fn webhook(
+    state: &State<OctoAppState>,
+    event: WebHook<Event>,
+) -> OctoAppResult<OctoAppResponse> {
+    // Ignore events triggered by bots (including this app) to prevent loops
+    if event.is_bot() {
+        tracing::debug!("Ignoring event from bot: {:?}", event.sender());
+        return Ok(OctoAppResponse::Ignored("Event from a bot".to_string()));
+    }
+
+    // The variant the payload was parsed as (independent of the header)
+    tracing::debug!("Parsed event: Event::{}", event.0.variant_name());
+
     // Get the Octocrab instance from the state
-    let octo = event.octocrab(state).await.unwrap();
+    let octo = event.octocrab(&state.config).await.unwrap();
     tracing::info!("Octocrab instance: {:?}", octo);
 
     match event.into_inner() {
         Event::Issues(issues) => {
             tracing::info!("Received an issue event: {:?}", issues.issue.id);
 
-            // Comment on the issue
-            octo.issues("42ByteLabs", "octoapp")
-                .create_comment(issues.issue.number, "Hello from OctoApp!")
-                .await
-                .unwrap();
+            // Comment on the issue (updating the comment on redelivery)
+            upsert_comment(
+                &octo,
+                "42ByteLabs",
+                "octoapp",
+                issues.issue.number,
+                "hello",
+                "Hello from OctoApp!",
+            )
+            .await?;
 
-            Ok(())
+            Ok(OctoAppResponse::Ok)
         }
         _ => {
             tracing::warn!("Received an unknown event");
-            Err(OctoAppError::UnknownError)
+            Ok(OctoAppResponse::Ignored("Unhandled event".to_string()))
         }
     }
 }
@@ -45,10 +62,11 @@ async fn main() -> Result<()> {
     dotenvy::dotenv()?;
 
     // Load the configuration (from environment variables)
-    let mut config = OctoAppConfig::init().build()?;
+    let config = OctoAppConfig::init().build()?;
     // Install the configuration and fetch all the installations
     // of the GitHub App (if any).
-    config.install().await?;
+    let installations = config.install().await?;
+    tracing::info!("Found {} installations", installations.len());
 
     // This will create an Octocrab instance with the required authentication
     // information. Note, this is done after `.install()` so if an installation
@@ -0,0 +1,104 @@
+//! # App Metadata
+//!
+//! The metadata of the GitHub App (`GET /app`), useful for checking at startup
+//! that the app registration has the permissions and event subscriptions the
+//! app expects.
+//!
+//! ```no_run
+//! # async fn run(config: octoapp::OctoAppConfig) -> Result<(), octoapp::OctoAppError> {
+//! use octoapp::events::EventKind;
+//!
+//! let app = config.app_metadata().await?;
+//! app.requires_events(&[EventKind::Issues, EventKind::PullRequest])?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{events::EventKind, OctoAppError};
+
+/// Events GitHub delivers to every app without subscribing to them
+const ALWAYS_SUBSCRIBED: &[EventKind] = &[
+    EventKind::GithubAppAuthorization,
+    EventKind::Installation,
+    EventKind::InstallationRepositories,
+];
+
+/// GitHub App Metadata
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AppMetadata {
+    /// The App ID
+    pub id: u64,
+    /// The slug of the app (used in the app URL)
+    pub slug: Option<String>,
+    /// The name of the app
+    pub name: String,
+    /// The permissions of the app (`issues` => `write`)
+    #[serde(default)]
+    pub permissions: HashMap<String, String>,
+    /// The events the app is subscribed to
+    #[serde(default)]
+    pub events: Vec<String>,
+}
+
+impl AppMetadata {
+    /// Check if the app is subscribed to the event
+    pub fn is_subscribed(&self, kind: EventKind) -> bool {
+        ALWAYS_SUBSCRIBED.contains(&kind) || self.events.iter().any(|e| e == kind.as_str())
+    }
+
+    /// Check the app is subscribed to all the events
+    ///
+    /// Returns `OctoAppError::MissingEvents` with the events the app is not
+    /// subscribed to.
+    pub fn requires_events(&self, kinds: &[EventKind]) -> Result<(), OctoAppError> {
+        let missing: Vec<EventKind> = kinds
+            .iter()
+            .filter(|kind| !self.is_subscribed(**kind))
+            .copied()
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(OctoAppError::MissingEvents(missing))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_events() {
+        let app: AppMetadata = serde_json::from_str(
+            r#"{
+                "id": 12345,
+                "slug": "octoapp",
+                "name": "OctoApp",
+                "permissions": {"issues": "write", "metadata": "read"},
+                "events": ["issues", "push"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.permissions.get("issues").map(String::as_str),
+            Some("write")
+        );
+        assert!(app.is_subscribed(EventKind::Issues));
+        assert!(app.is_subscribed(EventKind::Installation));
+        assert!(app
+            .requires_events(&[EventKind::Issues, EventKind::Push])
+            .is_ok());
+
+        match app.requires_events(&[EventKind::Issues, EventKind::PullRequest]) {
+            Err(OctoAppError::MissingEvents(missing)) => {
+                assert_eq!(missing, vec![EventKind::PullRequest])
+            }
+            other => panic!("Expected MissingEvents, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! # OctoApp Replay
+//!
+//! Replay a recorded webhook payload against a running app, signing it with
+//! the webhook secret the same way GitHub does.
+//!
+//! ```bash
+//! octoapp-replay <PAYLOAD> <EVENT> <URL> [SECRET]
+//! ```
+//!
+//! The secret defaults to the `WEBHOOK_SECRET` environment variable.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "Usage: octoapp-replay <PAYLOAD> <EVENT> <URL> [SECRET]
+
+Arguments:
+  <PAYLOAD>  Path to the recorded JSON payload
+  <EVENT>    The event name (X-GitHub-Event header, e.g. `issues`)
+  <URL>      The webhook URL of the running app
+  [SECRET]   The webhook secret (default: WEBHOOK_SECRET env var)";
+
+#[tokio::main]
+async fn main() {
+    if let Err(e) = run().await {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    let (payload, event, url) = match args.as_slice() {
+        [payload, event, url, ..] => (payload, event, url),
+        _ => return Err(USAGE.into()),
+    };
+    let secret = match args.get(3) {
+        Some(secret) => secret.clone(),
+        None => std::env::var("WEBHOOK_SECRET")
+            .map_err(|_| "Missing webhook secret (argument or WEBHOOK_SECRET env var)")?,
+    };
+
+    // The exact bytes are sent so the signature matches the recorded payload
+    let body = std::fs::read(payload)?;
+
+    let signature = octoapp::config::webhook_signature(&secret, &body);
+    let delivery = format!(
+        "replay-{}",
+        SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis()
+    );
+
+    let response = reqwest::Client::new()
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header(
+            "User-Agent",
+            concat!("octoapp-replay/", env!("CARGO_PKG_VERSION")),
+        )
+        .header("X-GitHub-Event", event)
+        .header("X-GitHub-Delivery", &delivery)
+        .header("X-Hub-Signature-256", signature)
+        .body(body)
+        .send()
+        .await?;
+
+    let status = response.status();
+    println!("{} ({}) -> {}", event, delivery, status);
+    let text = response.text().await?;
+    if !text.is_empty() {
+        println!("{}", text);
+    }
+
+    if !status.is_success() {
+        return Err(format!("The app responded with {}", status).into());
+    }
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! # Bot Comments
+//!
+//! Apps commonly keep a single comment on an issue or pull request up to date
+//! (for example a status or summary report). Posting a new comment for every
+//! event spams the issue, especially when GitHub redelivers a webhook.
+//!
+//! `upsert_comment` tags the comment with a hidden HTML marker and updates the
+//! existing bot comment with the marker if there is one, otherwise it creates
+//! it.
+//!
+//! ```no_run
+//! # use octoapp::comments::upsert_comment;
+//! # async fn run(client: octocrab::Octocrab) -> Result<(), octoapp::OctoAppError> {
+//! upsert_comment(&client, "42ByteLabs", "octoapp", 1, "octoapp-status", "All checks passed").await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use octocrab::{models::issues::Comment, Octocrab};
+
+use crate::OctoAppError;
+
+/// Get the hidden HTML marker for the marker name (`<!-- octoapp:{marker} -->`)
+pub fn comment_marker(marker: &str) -> String {
+    format!("<!-- octoapp:{} -->", marker)
+}
+
+/// Get the comment body tagged with the hidden marker
+fn marked_body(marker: &str, body: &str) -> String {
+    format!("{}\n{}", comment_marker(marker), body)
+}
+
+/// Check if the comment body contains the hidden marker
+fn has_marker(comment: Option<&str>, marker: &str) -> bool {
+    comment.is_some_and(|body| body.contains(&comment_marker(marker)))
+}
+
+/// Check if the comment was written by a bot (an app) and has the marker
+///
+/// Users can quote or copy the marker, so their comments are never updated.
+fn is_marked_comment(comment: &Comment, marker: &str) -> bool {
+    comment.user.r#type == "Bot" && has_marker(comment.body.as_deref(), marker)
+}
+
+/// Create or update the comment with the marker on an issue / pull request
+///
+/// All the comments written by bots on the issue are searched for the
+/// hidden marker, the first comment found is updated with the body,
+/// otherwise a new comment is created. The marker is added to the body so it is found next time.
+pub async fn upsert_comment(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    issue_number: u64,
+    marker: &str,
+    body: &str,
+) -> Result<Comment, OctoAppError> {
+    let issues = client.issues(owner, repo);
+    let body = marked_body(marker, body);
+
+    let page = issues
+        .list_comments(issue_number)
+        .per_page(100)
+        .send()
+        .await?;
+    let comments = client.all_pages(page).await?;
+
+    match comments
+        .into_iter()
+        .find(|comment| is_marked_comment(comment, marker))
+    {
+        Some(comment) => {
+            tracing::debug!("Updating comment {} on #{}", comment.id, issue_number);
+            Ok(issues.update_comment(comment.id, body).await?)
+        }
+        None => {
+            tracing::debug!("Creating comment on #{}", issue_number);
+            Ok(issues.create_comment(issue_number, body).await?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_marker() {
+        let body = marked_body("status", "All checks passed");
+        assert_eq!(body, "<!-- octoapp:status -->\nAll checks passed");
+
+        assert!(has_marker(Some(&body), "status"));
+        assert!(!has_marker(Some(&body), "summary"));
+        assert!(!has_marker(Some("All checks passed"), "status"));
+        assert!(!has_marker(None, "status"));
+    }
+
+    #[test]
+    fn test_is_marked_comment() {
+        let data = std::fs::read_to_string(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("fixtures")
+                .join("commit_comment_created.json"),
+        )
+        .unwrap();
+        let payload: serde_json::Value = serde_json::from_str(&data).unwrap();
+        let comment = |kind: &str| {
+            let mut comment = payload["comment"].clone();
+            comment["body"] = marked_body("status", "All checks passed").into();
+            comment["user"]["type"] = kind.into();
+            serde_json::from_value::<Comment>(comment).unwrap()
+        };
+
+        assert!(is_marked_comment(&comment("Bot"), "status"));
+        assert!(!is_marked_comment(&comment("Bot"), "summary"));
+        // A user quoting the marker
+        assert!(!is_marked_comment(&comment("User"), "status"));
+    }
+}
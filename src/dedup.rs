@@ -0,0 +1,190 @@
+//! # Webhook Delivery Deduplication
+//!
+//! GitHub delivers webhooks at least once and will redeliver on timeouts,
+//! so the same delivery (`X-GitHub-Delivery` header) can be received more
+//! than once. The `DeliveryDedup` is consulted by the integrations before
+//! calling the handler so duplicate deliveries are acknowledged but skipped.
+//! Known duplicates are also dropped before the delivery is published (see
+//! `DedupStore::contains`).
+//!
+//! The storage is pluggable using the `DedupStore` trait (for example to use
+//! Redis when running multiple instances) and defaults to a bounded in-memory
+//! store.
+//!
+//! Note that a delivery is only recorded once it has been verified, parsed
+//! and accepted (so a redelivery of a rejected delivery is processed again),
+//! but a redelivery of an event whose handler failed is skipped.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// The default time a delivery is remembered for
+const DEDUP_TTL: Duration = Duration::from_secs(60 * 60);
+/// The default number of deliveries the in-memory store remembers
+const DEDUP_CAPACITY: usize = 10_000;
+
+/// Storage for the seen webhook deliveries
+#[async_trait::async_trait]
+pub trait DedupStore: Send + Sync {
+    /// Record the delivery ID, returning `true` if it was already seen within the TTL
+    async fn check_and_record(&self, delivery_id: &str, ttl: Duration) -> bool;
+
+    /// Check if the delivery ID was seen within the TTL, without recording it
+    ///
+    /// By default nothing is reported as seen, so duplicates are only caught
+    /// once the delivery is recorded (after it has been published).
+    async fn contains(&self, delivery_id: &str, ttl: Duration) -> bool {
+        let _ = (delivery_id, ttl);
+        false
+    }
+}
+
+/// In-memory deduplication store
+///
+/// Remembers up to `capacity` deliveries, evicting the oldest first.
+#[derive(Debug)]
+pub struct MemoryDedupStore {
+    capacity: usize,
+    inner: Mutex<MemoryDedupInner>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryDedupInner {
+    seen: HashMap<String, Instant>,
+    order: VecDeque<(String, Instant)>,
+}
+
+impl MemoryDedupStore {
+    /// Create a new in-memory store with the given capacity
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            inner: Mutex::new(MemoryDedupInner::default()),
+        }
+    }
+}
+
+impl MemoryDedupInner {
+    /// Drop the expired deliveries (oldest first)
+    fn expire(&mut self, now: Instant, ttl: Duration) {
+        while let Some((id, seen)) = self.order.front() {
+            if now.duration_since(*seen) < ttl {
+                break;
+            }
+            let id = id.clone();
+            self.seen.remove(&id);
+            self.order.pop_front();
+        }
+    }
+}
+
+impl Default for MemoryDedupStore {
+    fn default() -> Self {
+        Self::new(DEDUP_CAPACITY)
+    }
+}
+
+#[async_trait::async_trait]
+impl DedupStore for MemoryDedupStore {
+    async fn check_and_record(&self, delivery_id: &str, ttl: Duration) -> bool {
+        let now = Instant::now();
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.expire(now, ttl);
+
+        if inner.seen.contains_key(delivery_id) {
+            return true;
+        }
+
+        if inner.order.len() >= self.capacity {
+            if let Some((id, _)) = inner.order.pop_front() {
+                inner.seen.remove(&id);
+            }
+        }
+        inner.seen.insert(delivery_id.to_string(), now);
+        inner.order.push_back((delivery_id.to_string(), now));
+        false
+    }
+
+    async fn contains(&self, delivery_id: &str, ttl: Duration) -> bool {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.expire(Instant::now(), ttl);
+        inner.seen.contains_key(delivery_id)
+    }
+}
+
+/// Webhook Delivery Deduplication
+///
+/// Keyed by the `X-GitHub-Delivery` header.
+#[derive(Clone)]
+pub struct DeliveryDedup {
+    store: Arc<dyn DedupStore>,
+    ttl: Duration,
+}
+
+impl DeliveryDedup {
+    /// Create a new DeliveryDedup using a custom store
+    pub fn new(store: impl DedupStore + 'static) -> Self {
+        Self {
+            store: Arc::new(store),
+            ttl: DEDUP_TTL,
+        }
+    }
+
+    /// Create a new DeliveryDedup using the in-memory store
+    pub fn memory(capacity: usize) -> Self {
+        Self::new(MemoryDedupStore::new(capacity))
+    }
+
+    /// Set the time a delivery is remembered for (default: 1 hour)
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Check if the delivery is a duplicate, recording it if it isn't
+    pub async fn is_duplicate(&self, delivery_id: &str) -> bool {
+        let duplicate = self.store.check_and_record(delivery_id, self.ttl).await;
+        if duplicate {
+            tracing::info!("Skipping duplicate delivery: {}", delivery_id);
+        }
+        duplicate
+    }
+
+    /// Check if the delivery was already seen, without recording it
+    pub async fn is_seen(&self, delivery_id: &str) -> bool {
+        let seen = self.store.contains(delivery_id, self.ttl).await;
+        if seen {
+            tracing::info!("Skipping duplicate delivery: {}", delivery_id);
+        }
+        seen
+    }
+}
+
+impl Default for DeliveryDedup {
+    fn default() -> Self {
+        Self::memory(DEDUP_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_memory_dedup() {
+        let dedup = DeliveryDedup::memory(2);
+
+        assert!(!dedup.is_seen("delivery-1").await);
+        assert!(!dedup.is_duplicate("delivery-1").await);
+        assert!(dedup.is_seen("delivery-1").await);
+        assert!(dedup.is_duplicate("delivery-1").await);
+        assert!(!dedup.is_duplicate("delivery-2").await);
+
+        // Capacity is 2, so the oldest delivery is evicted
+        assert!(!dedup.is_duplicate("delivery-3").await);
+        assert!(!dedup.is_duplicate("delivery-1").await);
+    }
+}
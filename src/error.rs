@@ -10,10 +10,59 @@ pub enum OctoAppError {
     #[error("Missing required field: {0}")]
     MissingField(String),
 
+    /// Configuration Errors (all the problems found while validating)
+    #[error("Configuration Errors: {}", .0.join(", "))]
+    ConfigErrors(Vec<String>),
+
     /// Signature Errors
     #[error("Signature Error: {0}")]
     SignatureError(String),
 
+    /// Missing Event Header
+    #[error("Missing X-GitHub-Event header")]
+    MissingEventHeader,
+
+    /// Unsupported Media Type (the webhook body isn't JSON)
+    #[error("Unsupported Content-Type: {0}")]
+    UnsupportedMediaType(String),
+
+    /// Empty Body (the verified webhook body has no payload)
+    #[error("Empty webhook body, expected a JSON payload")]
+    EmptyBody,
+
+    /// Missing Installation (the payload isn't scoped to an installation)
+    #[error("Missing installation in the webhook payload")]
+    MissingInstallation,
+
+    /// Installation Not Allowed (not in the allowed installations)
+    #[error("Installation not allowed: {0}")]
+    InstallationNotAllowed(u64),
+
+    /// Unknown Event
+    #[error("Unknown event in X-GitHub-Event header: {0}")]
+    UnknownEvent(String),
+
+    /// Missing Event Subscriptions (the app is not subscribed to the events)
+    #[cfg(feature = "octocrab")]
+    #[error("Missing event subscriptions: {}", .0.iter().map(|e| e.as_str()).collect::<Vec<_>>().join(", "))]
+    MissingEvents(Vec<crate::events::EventKind>),
+
+    /// Duplicate Delivery (already processed)
+    #[error("Duplicate delivery: {0}")]
+    DuplicateDelivery(String),
+
+    /// Ignored Event (filtered out by the event kinds the app subscribes to)
+    #[error("Ignored event: {0}")]
+    IgnoredEvent(String),
+
+    /// Unsupported private key algorithm
+    #[error("Unsupported private key algorithm: {0}")]
+    UnsupportedKeyAlgorithm(String),
+
+    /// Parse Error (invalid configuration values)
+    #[error("Parse Error: {0}")]
+    ParseError(String),
+
     /// Webhook Secret Error
     #[error("Webhook Secret Error: {0}")]
     WebhookSecretError(String),
@@ -27,11 +76,48 @@ pub enum OctoAppError {
     #[error("Octocrab Error: {0}")]
     OctocrabError(#[from] octocrab::Error),
 
+    /// Invalid Credentials (the private key doesn't authenticate as the app)
+    #[cfg(feature = "octocrab")]
+    #[error("Invalid Credentials: {0}")]
+    InvalidCredentials(String),
+
     /// Octocrab Installation Error
     #[cfg(feature = "octocrab")]
     #[error("Octocrab Installation Error: {0}")]
     OctocrabInstallationError(u64),
 
+    /// HTTP Client Error
+    #[cfg(feature = "smee")]
+    #[error("HTTP Client Error: {0}")]
+    ReqwestError(#[from] reqwest::Error),
+
+    /// Proxy Error
+    #[cfg(feature = "proxy")]
+    #[error("Proxy Error: {0}")]
+    ProxyError(String),
+
+    /// Publish Error (failed to forward the event to the sink)
+    #[cfg(feature = "publish")]
+    #[error("Publish Error: {0}")]
+    PublishError(String),
+
+    /// OAuth Error (returned by GitHub during the OAuth web flow)
+    #[error("OAuth Error: {0}")]
+    OAuthError(String),
+
+    /// Invalid Form Payload (a form encoded webhook without a valid `payload` field)
+    #[error("Invalid form payload: {0}")]
+    InvalidFormPayload(String),
+
+    /// Invalid Webhook Payload (with the path of the field which failed)
+    #[error("Invalid webhook payload at `{path}`: {source}")]
+    PayloadError {
+        /// The path of the field in the payload
+        path: String,
+        /// The JSON error (including the line and column)
+        source: serde_json::Error,
+    },
+
     /// Serde Error
     #[error("JSON Serde Error: {0}")]
     JsonSerializationError(#[from] serde_json::Error),
@@ -47,3 +133,86 @@ pub enum OctoAppError {
     #[error("Unknown Error")]
     UnknownError,
 }
+
+impl From<std::num::ParseIntError> for OctoAppError {
+    fn from(value: std::num::ParseIntError) -> Self {
+        OctoAppError::ParseError(value.to_string())
+    }
+}
+
+impl OctoAppError {
+    /// The HTTP status code to answer a webhook delivery with for the error
+    ///
+    /// Shared by the integrations: `200` for deliveries which are skipped
+    /// on purpose, `4xx` for invalid requests (which GitHub doesn't need to
+    /// redeliver) and `5xx` for GitHub API and app errors.
+    pub fn http_status(&self) -> u16 {
+        match self {
+            // Already processed, acknowledge the delivery
+            Self::DuplicateDelivery(_) | Self::IgnoredEvent(_) => 200,
+            // Invalid requests from the client
+            Self::SignatureError(_) => 401,
+            Self::InstallationNotAllowed(_) => 403,
+            Self::LimitExceeded => 413,
+            Self::UnsupportedMediaType(_) => 415,
+            Self::MissingEventHeader
+            | Self::EmptyBody
+            | Self::MissingInstallation
+            | Self::UnknownEvent(_)
+            | Self::OAuthError(_)
+            | Self::InvalidFormPayload(_)
+            | Self::PayloadError { .. }
+            | Self::JsonSerializationError(_)
+            | Self::IoError(_) => 400,
+            // Upstream GitHub API errors
+            #[cfg(feature = "octocrab")]
+            Self::OctocrabError(_) => 502,
+            #[cfg(feature = "smee")]
+            Self::ReqwestError(_) => 502,
+            #[cfg(feature = "publish")]
+            Self::PublishError(_) => 502,
+            // App configuration / internal errors
+            Self::JsonWebTokenError(_)
+            | Self::MissingField(_)
+            | Self::ConfigErrors(_)
+            | Self::WebhookSecretError(_)
+            | Self::UnsupportedKeyAlgorithm(_)
+            | Self::ParseError(_)
+            | Self::UnknownError => 500,
+            #[cfg(feature = "octocrab")]
+            Self::OctocrabInstallationError(_)
+            | Self::InvalidCredentials(_)
+            | Self::MissingEvents(_) => 500,
+            #[cfg(feature = "proxy")]
+            Self::ProxyError(_) => 500,
+        }
+    }
+
+    /// Check if the error is transient and the request can be retried
+    ///
+    /// This covers GitHub server errors (5xx), rate limits (429 and the
+    /// secondary rate limit 403) and connection errors.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            #[cfg(feature = "octocrab")]
+            OctoAppError::OctocrabError(octocrab::Error::GitHub { source, .. }) => {
+                let status = source.status_code;
+                status.is_server_error()
+                    || status == http::StatusCode::TOO_MANY_REQUESTS
+                    || (status == http::StatusCode::FORBIDDEN
+                        && source.message.to_lowercase().contains("rate limit"))
+            }
+            #[cfg(feature = "octocrab")]
+            OctoAppError::OctocrabError(
+                octocrab::Error::Hyper { .. } | octocrab::Error::Service { .. },
+            ) => true,
+            #[cfg(feature = "smee")]
+            OctoAppError::ReqwestError(e) => {
+                e.is_timeout()
+                    || e.is_connect()
+                    || e.status().is_some_and(|status| status.is_server_error())
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! # Security Alert Event Helpers
+//!
+//! Helpers for the `dependabot_alert`, `secret_scanning_alert` and
+//! `code_scanning_alert` events (and `secret_scanning_alert_location`), such
+//! as the alert number, state and severity.
+
+use super::{
+    octocrab_enum, payload_field as field,
+    payloads::{
+        CodeScanningAlertEvent, DependabotAlertEvent, SecretScanningAlertEvent,
+        SecretScanningAlertLocationEvent,
+    },
+};
+
+string_enum! {
+    /// Security Alert State
+    AlertState {
+        /// Open
+        Open => "open",
+        /// Fixed
+        Fixed => "fixed",
+        /// Dismissed (including `auto_dismissed`)
+        Dismissed => "dismissed" | "auto_dismissed",
+        /// Resolved (secret scanning)
+        Resolved => "resolved",
+    }
+}
+
+string_enum! {
+    /// Security Alert Severity
+    Severity {
+        /// Low
+        Low => "low",
+        /// Medium (`moderate` for GitHub advisories)
+        Medium => "medium" | "moderate",
+        /// High
+        High => "high",
+        /// Critical
+        Critical => "critical",
+    }
+}
+
+string_enum! {
+    /// Secret Scanning Location Type
+    SecretLocationKind {
+        /// Commit
+        Commit => "commit",
+        /// Wiki Commit
+        WikiCommit => "wiki_commit",
+        /// Issue Title
+        IssueTitle => "issue_title",
+        /// Issue Body
+        IssueBody => "issue_body",
+        /// Issue Comment
+        IssueComment => "issue_comment",
+        /// Discussion Title
+        DiscussionTitle => "discussion_title",
+        /// Discussion Body
+        DiscussionBody => "discussion_body",
+        /// Discussion Comment
+        DiscussionComment => "discussion_comment",
+        /// Pull Request Title
+        PullRequestTitle => "pull_request_title",
+        /// Pull Request Body
+        PullRequestBody => "pull_request_body",
+        /// Pull Request Comment
+        PullRequestComment => "pull_request_comment",
+        /// Pull Request Review
+        PullRequestReview => "pull_request_review",
+        /// Pull Request Review Comment
+        PullRequestReviewComment => "pull_request_review_comment",
+    }
+}
+
+/// Secret Scanning Location (where the secret was found)
+///
+/// The path, lines and commit are only set for `commit` and `wiki_commit`
+/// locations, the other types point at an issue, discussion or pull request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretLocation {
+    /// The type of the location
+    pub kind: SecretLocationKind,
+    /// The path of the file
+    pub path: Option<String>,
+    /// The line the secret starts on
+    pub start_line: Option<u64>,
+    /// The line the secret ends on
+    pub end_line: Option<u64>,
+    /// The SHA of the commit
+    pub commit_sha: Option<String>,
+}
+
+/// Helpers for the Secret Scanning Alert Location Event
+pub trait SecretScanningAlertLocationEventExt {
+    /// Get the number of the alert the location belongs to
+    fn alert_number(&self) -> Option<u64>;
+    /// Get the location the secret was found at
+    fn location(&self) -> Option<SecretLocation>;
+}
+
+impl SecretScanningAlertLocationEventExt for SecretScanningAlertLocationEvent {
+    fn alert_number(&self) -> Option<u64> {
+        self.alert.get("number")?.as_u64()
+    }
+
+    fn location(&self) -> Option<SecretLocation> {
+        let details = self.location.get("details");
+        let line = |name: &str| details?.get(name)?.as_u64();
+
+        Some(SecretLocation {
+            kind: field(&self.location, &["type"])?,
+            path: details.and_then(|details| field(details, &["path"])),
+            start_line: line("start_line"),
+            end_line: line("end_line"),
+            commit_sha: details.and_then(|details| field(details, &["commit_sha"])),
+        })
+    }
+}
+
+/// Helpers for the security alert events
+pub trait AlertEventExt {
+    /// Get the state of the alert
+    fn state(&self) -> Option<AlertState>;
+    /// Get the severity of the alert (if the alert type has one)
+    fn severity(&self) -> Option<Severity>;
+}
+
+impl AlertEventExt for DependabotAlertEvent {
+    fn state(&self) -> Option<AlertState> {
+        field(&self.alert, &["state"])
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        field(&self.alert, &["security_advisory", "severity"])
+            .or_else(|| field(&self.alert, &["security_vulnerability", "severity"]))
+    }
+}
+
+impl AlertEventExt for SecretScanningAlertEvent {
+    fn state(&self) -> Option<AlertState> {
+        field(&self.alert, &["state"])
+    }
+
+    /// Secret scanning alerts don't have a severity
+    fn severity(&self) -> Option<Severity> {
+        None
+    }
+}
+
+/// The code scanning alert is typed by Octocrab, so its enums are converted
+impl AlertEventExt for CodeScanningAlertEvent {
+    fn state(&self) -> Option<AlertState> {
+        Some(octocrab_enum(&self.alert.state))
+    }
+
+    /// The security severity of the rule (only set for security queries)
+    fn severity(&self) -> Option<Severity> {
+        self.alert
+            .rule
+            .security_severity_level
+            .as_ref()
+            .map(octocrab_enum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dependabot_alert_helpers() {
+        let event: DependabotAlertEvent = serde_json::from_str(
+            r#"{
+                "action": "auto_dismissed",
+                "alert": {
+                    "number": 3,
+                    "state": "auto_dismissed",
+                    "security_advisory": {"ghsa_id": "GHSA-xxxx-xxxx-xxxx", "severity": "moderate"}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.state(), Some(AlertState::Dismissed));
+        assert_eq!(event.severity(), Some(Severity::Medium));
+
+        let state: AlertState = serde_json::from_str(r#""quarantined""#).unwrap();
+        assert_eq!(state, AlertState::Other("quarantined".to_string()));
+    }
+
+    #[test]
+    fn test_code_scanning_alert_helpers() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/code_scanning_alert_created.json"
+        );
+        let event = crate::WebHook::<CodeScanningAlertEvent>::from_json_file(path)
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(event.state(), Some(AlertState::Open));
+        assert_eq!(event.severity(), Some(Severity::High));
+    }
+
+    #[test]
+    fn test_secret_scanning_alert_location() {
+        let event: SecretScanningAlertLocationEvent = serde_json::from_str(
+            r#"{
+                "action": "created",
+                "alert": {"number": 7, "secret_type": "github_personal_access_token"},
+                "location": {
+                    "type": "commit",
+                    "details": {
+                        "path": "src/config.rs",
+                        "start_line": 12,
+                        "end_line": 12,
+                        "start_column": 20,
+                        "end_column": 60,
+                        "blob_sha": "af5626b4a114abcb82d63db7c8082c3c4756e51b",
+                        "commit_sha": "f14d7debf9775f957cf4f1e8176da0786431f72b"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.alert_number(), Some(7));
+        assert_eq!(
+            event.location(),
+            Some(SecretLocation {
+                kind: SecretLocationKind::Commit,
+                path: Some("src/config.rs".to_string()),
+                start_line: Some(12),
+                end_line: Some(12),
+                commit_sha: Some("f14d7debf9775f957cf4f1e8176da0786431f72b".to_string()),
+            })
+        );
+
+        let event: SecretScanningAlertLocationEvent = serde_json::from_str(
+            r#"{
+                "action": "created",
+                "alert": {"number": 8},
+                "location": {"type": "gist_comment", "details": {"gist_comment_url": "https://example.com"}}
+            }"#,
+        )
+        .unwrap();
+        let location = event.location().unwrap();
+        assert_eq!(
+            location.kind,
+            SecretLocationKind::Other("gist_comment".to_string())
+        );
+        assert_eq!(location.path, None);
+    }
+}
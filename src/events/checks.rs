@@ -0,0 +1,180 @@
+//! # Check Run / Check Suite Event Helpers
+//!
+//! The `status` and `conclusion` of check runs and suites, for apps which
+//! react to CI results.
+
+pub use octocrab::models::webhook_events::payload::CheckSuiteWebhookEventAction as CheckSuiteAction;
+
+use super::{
+    payload_field as field,
+    payloads::{CheckRunEvent, CheckSuiteEvent},
+    WebHook,
+};
+
+string_enum! {
+    /// Check Run / Check Suite Status
+    CheckRunStatus {
+        /// Queued
+        Queued => "queued",
+        /// In Progress
+        InProgress => "in_progress",
+        /// Completed
+        Completed => "completed",
+    }
+}
+
+string_enum! {
+    /// Check Run / Check Suite Conclusion
+    CheckConclusion {
+        /// Success
+        Success => "success",
+        /// Failure
+        Failure => "failure",
+        /// Neutral
+        Neutral => "neutral",
+        /// Cancelled
+        Cancelled => "cancelled",
+        /// Timed Out
+        TimedOut => "timed_out",
+        /// Action Required
+        ActionRequired => "action_required",
+        /// Stale
+        Stale => "stale",
+        /// Skipped
+        Skipped => "skipped",
+    }
+}
+
+/// Helpers for the Check Run Event
+pub trait CheckRunEventExt {
+    /// Get the status of the check run
+    fn status(&self) -> Option<CheckRunStatus>;
+    /// Get the conclusion of the check run (only set once completed)
+    fn conclusion(&self) -> Option<CheckConclusion>;
+}
+
+impl CheckRunEventExt for CheckRunEvent {
+    fn status(&self) -> Option<CheckRunStatus> {
+        field(&self.check_run, &["status"])
+    }
+
+    fn conclusion(&self) -> Option<CheckConclusion> {
+        field(&self.check_run, &["conclusion"])
+    }
+}
+
+/// Helpers for the Check Suite Event
+pub trait CheckSuiteEventExt {
+    /// Get the action of the event
+    fn action(&self) -> &CheckSuiteAction;
+    /// Get the status of the check suite
+    fn status(&self) -> Option<CheckRunStatus>;
+    /// Get the conclusion of the check suite (only set once completed)
+    fn conclusion(&self) -> Option<CheckConclusion>;
+    /// Get the head commit SHA of the check suite
+    fn head_sha(&self) -> Option<&str>;
+    /// Get the owner and name of the repository (from the check suite URL)
+    fn repository(&self) -> Option<(&str, &str)>;
+}
+
+impl CheckSuiteEventExt for CheckSuiteEvent {
+    fn action(&self) -> &CheckSuiteAction {
+        &self.action
+    }
+
+    fn status(&self) -> Option<CheckRunStatus> {
+        field(&self.check_suite, &["status"])
+    }
+
+    fn conclusion(&self) -> Option<CheckConclusion> {
+        field(&self.check_suite, &["conclusion"])
+    }
+
+    fn head_sha(&self) -> Option<&str> {
+        self.check_suite.get("head_sha")?.as_str()
+    }
+
+    fn repository(&self) -> Option<(&str, &str)> {
+        // `{base}/repos/{owner}/{repo}/check-suites/{id}`
+        let url = self.check_suite.get("url")?.as_str()?;
+        let mut segments = url.split_once("/repos/")?.1.split('/');
+        Some((segments.next()?, segments.next()?))
+    }
+}
+
+impl WebHook<CheckSuiteEvent> {
+    /// Create a check run for the head commit of the check suite
+    ///
+    /// Apps receive `requested` / `rerequested` check suites and create the
+    /// check runs with the installation client (`webhook.octocrab(&config)`).
+    /// The check run is `queued` until the app updates it.
+    pub async fn create_check_run(
+        &self,
+        octo: &octocrab::Octocrab,
+        name: impl Into<String>,
+    ) -> Result<octocrab::models::checks::CheckRun, crate::OctoAppError> {
+        let (owner, repo) = self
+            .0
+            .repository()
+            .ok_or_else(|| crate::OctoAppError::MissingField("check_suite.url".to_string()))?;
+        let head_sha = self
+            .0
+            .head_sha()
+            .ok_or_else(|| crate::OctoAppError::MissingField("check_suite.head_sha".to_string()))?;
+
+        Ok(octo
+            .checks(owner, repo)
+            .create_check_run(name, head_sha)
+            .send()
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_run_status_and_conclusion() {
+        let event: CheckRunEvent = serde_json::from_str(
+            r#"{"action": "completed", "check_run": {"status": "completed", "conclusion": "startup_failure"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.status(), Some(CheckRunStatus::Completed));
+        assert_eq!(
+            event.conclusion(),
+            Some(CheckConclusion::Other("startup_failure".to_string()))
+        );
+
+        let conclusion: CheckConclusion = serde_json::from_str(r#""timed_out""#).unwrap();
+        assert_eq!(conclusion, CheckConclusion::TimedOut);
+        assert_eq!(
+            serde_json::to_string(&conclusion).unwrap(),
+            r#""timed_out""#
+        );
+    }
+
+    #[test]
+    fn test_check_suite_rerequested() {
+        let event: CheckSuiteEvent = serde_json::from_str(
+            r#"{
+                "action": "rerequested",
+                "check_suite": {
+                    "status": "completed",
+                    "conclusion": "failure",
+                    "head_sha": "ec26c3e57ca3a959ca5aad62de7213c562f8c821",
+                    "url": "https://api.github.com/repos/42ByteLabs/octoapp/check-suites/118578147"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.action(), &CheckSuiteAction::Rerequested);
+        assert_eq!(
+            event.head_sha(),
+            Some("ec26c3e57ca3a959ca5aad62de7213c562f8c821")
+        );
+        assert_eq!(event.repository(), Some(("42ByteLabs", "octoapp")));
+    }
+}
@@ -0,0 +1,90 @@
+//! # Deployment Status Event Helpers
+//!
+//! The state, environment, target URL and commit of a deployment status
+//! update.
+
+use super::{payload_field as field, payloads::DeploymentStatusEvent};
+
+string_enum! {
+    /// Deployment Status State
+    DeploymentState {
+        /// Pending
+        Pending => "pending",
+        /// Queued
+        Queued => "queued",
+        /// In Progress
+        InProgress => "in_progress",
+        /// Success
+        Success => "success",
+        /// Failure
+        Failure => "failure",
+        /// Error
+        Error => "error",
+        /// Inactive
+        Inactive => "inactive",
+    }
+}
+
+/// Helpers for the Deployment Status Event
+pub trait DeploymentStatusEventExt {
+    /// Get the state of the deployment status
+    fn state(&self) -> Option<DeploymentState>;
+    /// Get the environment deployed to (e.g. `production`)
+    fn environment(&self) -> Option<&str>;
+    /// Get the target URL of the deployment status (e.g. the logs)
+    fn target_url(&self) -> Option<&str>;
+    /// Get the commit SHA of the deployment
+    fn deployment_sha(&self) -> Option<&str>;
+}
+
+impl DeploymentStatusEventExt for DeploymentStatusEvent {
+    fn state(&self) -> Option<DeploymentState> {
+        field(&self.deployment_status, &["state"])
+    }
+
+    fn environment(&self) -> Option<&str> {
+        self.deployment_status
+            .get("environment")
+            .or_else(|| self.deployment.get("environment"))?
+            .as_str()
+    }
+
+    fn target_url(&self) -> Option<&str> {
+        self.deployment_status
+            .get("target_url")?
+            .as_str()
+            .filter(|url| !url.is_empty())
+    }
+
+    fn deployment_sha(&self) -> Option<&str> {
+        self.deployment.get("sha")?.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deployment_status() {
+        let event: DeploymentStatusEvent = serde_json::from_str(
+            r#"{
+                "action": "created",
+                "deployment": {"sha": "a10867b14bb761a232cd80139fbd4c0d33264240", "environment": "production"},
+                "deployment_status": {"state": "in_progress", "environment": "production", "target_url": ""}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.state(), Some(DeploymentState::InProgress));
+        assert_eq!(event.environment(), Some("production"));
+        assert_eq!(event.target_url(), None);
+        assert_eq!(
+            event.deployment_sha(),
+            Some("a10867b14bb761a232cd80139fbd4c0d33264240")
+        );
+
+        let state: DeploymentState = serde_json::from_str(r#""destroyed""#).unwrap();
+        assert_eq!(state, DeploymentState::Other("destroyed".to_string()));
+    }
+}
@@ -0,0 +1,119 @@
+//! # Discussion / Discussion Comment Event Helpers
+//!
+//! The category and answer state of GitHub Discussions, and whether a
+//! discussion comment is the chosen answer.
+
+pub use octocrab::models::webhook_events::payload::DiscussionWebhookEventAction as DiscussionAction;
+
+use super::payloads::{DiscussionCommentEvent, DiscussionEvent};
+
+/// Get the slug of the discussion category
+fn category_slug(discussion: &serde_json::Value) -> Option<&str> {
+    discussion.get("category")?.get("slug")?.as_str()
+}
+
+/// Check if the discussion has a chosen answer
+fn is_answered(discussion: &serde_json::Value) -> bool {
+    discussion
+        .get("answer_chosen_at")
+        .is_some_and(|answer| !answer.is_null())
+}
+
+/// Get the ID of the chosen answer comment (`#discussioncomment-{id}` in the answer URL)
+fn answer_comment_id(discussion: &serde_json::Value) -> Option<u64> {
+    discussion
+        .get("answer_html_url")?
+        .as_str()?
+        .rsplit_once("#discussioncomment-")?
+        .1
+        .parse()
+        .ok()
+}
+
+/// Helpers for the Discussion Event
+pub trait DiscussionEventExt {
+    /// Get the slug of the discussion category
+    fn category_slug(&self) -> Option<&str>;
+    /// Check if the discussion has a chosen answer
+    fn is_answered(&self) -> bool;
+    /// Get the ID of the comment chosen as the answer
+    fn answer_chosen_comment_id(&self) -> Option<u64>;
+}
+
+impl DiscussionEventExt for DiscussionEvent {
+    fn category_slug(&self) -> Option<&str> {
+        category_slug(&self.discussion)
+    }
+
+    fn is_answered(&self) -> bool {
+        match self.action {
+            DiscussionAction::Answered => true,
+            DiscussionAction::Unanswered => false,
+            _ => is_answered(&self.discussion),
+        }
+    }
+
+    fn answer_chosen_comment_id(&self) -> Option<u64> {
+        match self.action {
+            DiscussionAction::Unanswered => None,
+            // The `answered` action includes the answer comment
+            _ => self
+                .answer
+                .as_ref()
+                .and_then(|answer| answer.get("id")?.as_u64())
+                .or_else(|| answer_comment_id(&self.discussion)),
+        }
+    }
+}
+
+/// Helpers for the Discussion Comment Event
+pub trait DiscussionCommentEventExt {
+    /// Get the slug of the discussion category
+    fn category_slug(&self) -> Option<&str>;
+    /// Check if the discussion has a chosen answer
+    fn is_answered(&self) -> bool;
+    /// Check if the comment is the chosen answer of the discussion
+    fn is_answer(&self) -> bool;
+}
+
+impl DiscussionCommentEventExt for DiscussionCommentEvent {
+    fn category_slug(&self) -> Option<&str> {
+        category_slug(&self.discussion)
+    }
+
+    fn is_answered(&self) -> bool {
+        is_answered(&self.discussion)
+    }
+
+    fn is_answer(&self) -> bool {
+        let id = self.comment.get("id").and_then(|id| id.as_u64());
+        id.is_some() && id == answer_comment_id(&self.discussion)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discussion_event_answered() {
+        let event: DiscussionEvent = serde_json::from_str(
+            r#"{
+                "action": "answered",
+                "answer": {"id": 1234, "body": "Use the `smee` feature"},
+                "discussion": {
+                    "number": 7,
+                    "category": {"id": 1, "name": "Q&A", "slug": "q-a", "is_answerable": true},
+                    "answer_html_url": "https://github.com/42ByteLabs/octoapp/discussions/7#discussioncomment-1234",
+                    "answer_chosen_at": "2024-01-01T00:00:00Z"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.action, DiscussionAction::Answered);
+        assert_eq!(event.category_slug(), Some("q-a"));
+        assert!(event.is_answered());
+        assert_eq!(event.answer_chosen_comment_id(), Some(1234));
+    }
+}
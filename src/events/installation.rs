@@ -0,0 +1,54 @@
+//! # Installation Event Helpers
+
+pub use octocrab::models::webhook_events::payload::InstallationWebhookEventAction as InstallationAction;
+
+use super::payloads::InstallationEvent;
+
+/// Helpers for the Installation Event
+pub trait InstallationEventExt {
+    /// Get the action of the event
+    fn action(&self) -> &InstallationAction;
+    /// Check if the app was installed or unsuspended (`created` / `unsuspend`)
+    fn is_installed(&self) -> bool;
+    /// Check if the app was uninstalled or suspended (`deleted` / `suspend`)
+    ///
+    /// The installation can't be used to access the API after this.
+    fn is_removed(&self) -> bool;
+}
+
+impl InstallationEventExt for InstallationEvent {
+    fn action(&self) -> &InstallationAction {
+        &self.action
+    }
+
+    fn is_installed(&self) -> bool {
+        matches!(
+            self.action,
+            InstallationAction::Created | InstallationAction::Unsuspend
+        )
+    }
+
+    fn is_removed(&self) -> bool {
+        matches!(
+            self.action,
+            InstallationAction::Deleted | InstallationAction::Suspend
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebHook;
+
+    #[test]
+    fn test_installation_event_helpers() {
+        let json = r#"{"action": "suspend", "installation": {"id": 42}, "repositories": []}"#;
+        let webhook = WebHook::<InstallationEvent>::from_json_str(json).unwrap();
+
+        assert_eq!(webhook.installation(), 42);
+        assert_eq!(webhook.0.action(), &InstallationAction::Suspend);
+        assert!(webhook.0.is_removed());
+        assert!(!webhook.0.is_installed());
+    }
+}
@@ -0,0 +1,54 @@
+//! # Issues Event Helpers
+
+use octocrab::models::{webhook_events::payload::IssuesWebhookEventAction, Label};
+
+use super::payloads::IssuesEvent;
+
+/// Helpers for the Issues Event
+pub trait IssuesEventExt {
+    /// Get the label added to the issue (`labeled` action)
+    fn added_label(&self) -> Option<&Label>;
+    /// Get the label removed from the issue (`unlabeled` action)
+    fn removed_label(&self) -> Option<&Label>;
+    /// Check if the issue currently has the label with the given name
+    fn has_label(&self, name: &str) -> bool;
+}
+
+impl IssuesEventExt for IssuesEvent {
+    fn added_label(&self) -> Option<&Label> {
+        match self.action {
+            IssuesWebhookEventAction::Labeled => self.label.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn removed_label(&self) -> Option<&Label> {
+        match self.action {
+            IssuesWebhookEventAction::Unlabeled => self.label.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn has_label(&self, name: &str) -> bool {
+        self.issue.labels.iter().any(|label| label.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebHook;
+
+    #[test]
+    fn test_issues_event_labels() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/issues_labeled.json");
+        let issues = WebHook::<IssuesEvent>::from_json_file(path)
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(issues.added_label().map(|l| l.name.as_str()), Some("bug"));
+        assert!(issues.removed_label().is_none());
+        assert!(issues.has_label("needs-triage"));
+        assert!(!issues.has_label("wontfix"));
+    }
+}
@@ -0,0 +1,261 @@
+//! # Webhook Event Kinds
+//!
+//! The kind of a webhook event, as sent by GitHub in the `X-GitHub-Event` header.
+
+use std::{fmt::Display, str::FromStr};
+
+use super::Event;
+
+/// Generate the `EventKind` enum and the mapping to / from the `Event` enum
+/// and the GitHub event names.
+macro_rules! event_kinds {
+    ($($(#[$doc:meta])* $kind:ident => $name:literal,)*) => {
+        /// Webhook Event Kind
+        ///
+        /// This enum represents the kind of event GitHub sends to the webhook
+        /// without the payload.
+        #[derive(
+            Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+        )]
+        pub enum EventKind {
+            $(
+                $(#[$doc])*
+                #[serde(rename = $name)]
+                $kind,
+            )*
+        }
+
+        impl EventKind {
+            /// All the event kinds
+            pub const ALL: &'static [EventKind] = &[$(EventKind::$kind,)*];
+
+            /// Get the GitHub event name (`X-GitHub-Event` header value)
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(EventKind::$kind => $name,)*
+                }
+            }
+        }
+
+        impl FromStr for EventKind {
+            type Err = crate::OctoAppError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($name => Ok(EventKind::$kind),)*
+                    _ => Err(crate::OctoAppError::UnknownEvent(s.to_string())),
+                }
+            }
+        }
+
+        impl Event {
+            /// Get the kind of the event
+            pub fn kind(&self) -> EventKind {
+                match self {
+                    $(Event::$kind(_) => EventKind::$kind,)*
+                }
+            }
+
+            /// Get the name of the `Event` variant (e.g. `PullRequest`)
+            ///
+            /// This is the variant serde matched the payload to, which (as `Event`
+            /// is untagged) can differ from the `X-GitHub-Event` header.
+            pub fn variant_name(&self) -> &'static str {
+                match self {
+                    $(Event::$kind(_) => stringify!($kind),)*
+                }
+            }
+
+            /// Deserialize the payload into the event of the given kind
+            ///
+            /// Unlike deserializing the (untagged) `Event` directly, the payload
+            /// is only deserialized into the matching variant. This is useful for
+            /// events loaded from storage where the event name was kept.
+            pub fn from_value(
+                kind: EventKind,
+                value: serde_json::Value,
+            ) -> Result<Event, crate::OctoAppError> {
+                Ok(match kind {
+                    $(EventKind::$kind => Event::$kind(serde_json::from_value(value)?),)*
+                })
+            }
+        }
+    };
+}
+
+event_kinds! {
+    /// Branch Protection Rule Event
+    BranchProtectionRule => "branch_protection_rule",
+    /// Check Run Event
+    CheckRun => "check_run",
+    /// Check Suite Event
+    CheckSuite => "check_suite",
+    /// Code Scanning Alert Event
+    CodeScanningAlert => "code_scanning_alert",
+    /// Commit Comment Event
+    CommitComment => "commit_comment",
+    /// Create Event
+    Create => "create",
+    /// Delete Event
+    Delete => "delete",
+    /// Dependabot Alert Event
+    DependabotAlert => "dependabot_alert",
+    /// Deploy Key Event
+    DeployKey => "deploy_key",
+    /// Deployment Protection Rule Event
+    DeploymentProtectionRule => "deployment_protection_rule",
+    /// Deployment Status Event
+    DeploymentStatus => "deployment_status",
+    /// Deployment Event
+    Deployment => "deployment",
+    /// Discussion Comment Event
+    DiscussionComment => "discussion_comment",
+    /// Discussion Event
+    Discussion => "discussion",
+    /// Fork Event
+    Fork => "fork",
+    /// GitHub App Authorization Event
+    GithubAppAuthorization => "github_app_authorization",
+    /// Gollum Event
+    Gollum => "gollum",
+    /// Installation Repositories Event
+    InstallationRepositories => "installation_repositories",
+    /// Installation Target Event
+    InstallationTarget => "installation_target",
+    /// Installation Event
+    Installation => "installation",
+    /// Issue Comment Event
+    IssueComment => "issue_comment",
+    /// Issues Event
+    Issues => "issues",
+    /// Label Event
+    Label => "label",
+    /// Marketplace Purchase Event
+    MarketplacePurchase => "marketplace_purchase",
+    /// Member Event
+    Member => "member",
+    /// Membership Event
+    Membership => "membership",
+    /// Merge Group Event
+    MergeGroup => "merge_group",
+    /// Meta Event
+    Meta => "meta",
+    /// Milestone Event
+    Milestone => "milestone",
+    /// Organization Block Event
+    OrgBlock => "org_block",
+    /// Organization Event
+    Organization => "organization",
+    /// Package Event
+    Package => "package",
+    /// Page Build Event
+    PageBuild => "page_build",
+    /// Personal Access Token Request Event
+    PersonalAccessTokenRequest => "personal_access_token_request",
+    /// Ping Event
+    Ping => "ping",
+    /// Project Card Event
+    ProjectCard => "project_card",
+    /// Project Column Event
+    ProjectColumn => "project_column",
+    /// Project V2 Item Event
+    ProjectsV2Item => "projects_v2_item",
+    /// Project V2 Event
+    ProjectsV2 => "projects_v2",
+    /// Public Event
+    Public => "public",
+    /// Pull Request Review Comment Event
+    PullRequestReviewComment => "pull_request_review_comment",
+    /// Pull Request Review Thread Event
+    PullRequestReviewThread => "pull_request_review_thread",
+    /// Pull Request Review Event
+    PullRequestReview => "pull_request_review",
+    /// Pull Request Event
+    PullRequest => "pull_request",
+    /// Push Event
+    Push => "push",
+    /// Registry Package Event
+    RegistryPackage => "registry_package",
+    /// Release Event
+    Release => "release",
+    /// Repository Advisory Event
+    RepositoryAdvisory => "repository_advisory",
+    /// Repository Import Event
+    RepositoryImport => "repository_import",
+    /// Repository Vulnerability Alert Event
+    RepositoryVulnerabilityAlert => "repository_vulnerability_alert",
+    /// Repository Event
+    Repository => "repository",
+    /// Secret Scanning Alert Location Event
+    SecretScanningAlertLocation => "secret_scanning_alert_location",
+    /// Secret Scanning Alert Event
+    SecretScanningAlert => "secret_scanning_alert",
+    /// Security Advisory Event
+    SecurityAdvisory => "security_advisory",
+    /// Security And Analysis Event
+    SecurityAndAnalysis => "security_and_analysis",
+    /// Sponsorship Event
+    Sponsorship => "sponsorship",
+    /// Star Event
+    Star => "star",
+    /// Status Event
+    Status => "status",
+    /// Team Add Event
+    TeamAdd => "team_add",
+    /// Team Event
+    Team => "team",
+    /// Watch Event
+    Watch => "watch",
+    /// Workflow Dispatch Event
+    WorkflowDispatch => "workflow_dispatch",
+    /// Workflow Job Event
+    WorkflowJob => "workflow_job",
+}
+
+impl Display for EventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<(EventKind, serde_json::Value)> for Event {
+    type Error = crate::OctoAppError;
+
+    fn try_from((kind, value): (EventKind, serde_json::Value)) -> Result<Self, Self::Error> {
+        Event::from_value(kind, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_kind_names() {
+        for kind in EventKind::ALL {
+            assert_eq!(EventKind::from_str(kind.as_str()).unwrap(), *kind);
+            assert_eq!(
+                serde_json::to_string(kind).unwrap(),
+                format!("\"{}\"", kind)
+            );
+        }
+        assert_eq!(EventKind::PullRequest.to_string(), "pull_request");
+        assert!(EventKind::from_str("not_an_event").is_err());
+    }
+
+    #[test]
+    fn test_event_from_value() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/issues_opened.json");
+        let value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+
+        let event = Event::from_value(EventKind::Issues, value.clone()).unwrap();
+        assert_eq!(event.kind(), EventKind::Issues);
+        assert_eq!(event.variant_name(), "Issues");
+
+        let event = Event::try_from((EventKind::Issues, value.clone())).unwrap();
+        assert!(matches!(event, Event::Issues(_)));
+
+        assert!(Event::from_value(EventKind::Push, value).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+//! # Merge Group Event Helpers
+//!
+//! The head and base commits of a merge queue group, which need their checks
+//! reported before the group is merged.
+
+pub use octocrab::models::webhook_events::payload::MergeGroupWebhookEventAction as MergeGroupAction;
+
+use super::payloads::MergeGroupEvent;
+
+/// Helpers for the Merge Group Event
+pub trait MergeGroupEventExt {
+    /// Get the action of the event (`checks_requested` or `destroyed`)
+    fn action(&self) -> &MergeGroupAction;
+    /// Get the SHA of the merge group commit (the one to run checks on)
+    fn head_sha(&self) -> Option<&str>;
+    /// Get the SHA of the base the merge group was created from
+    fn base_sha(&self) -> Option<&str>;
+    /// Get the ref of the merge group (`refs/heads/gh-readonly-queue/...`)
+    fn head_ref(&self) -> Option<&str>;
+}
+
+impl MergeGroupEventExt for MergeGroupEvent {
+    fn action(&self) -> &MergeGroupAction {
+        &self.action
+    }
+
+    fn head_sha(&self) -> Option<&str> {
+        self.merge_group.get("head_sha")?.as_str()
+    }
+
+    fn base_sha(&self) -> Option<&str> {
+        self.merge_group.get("base_sha")?.as_str()
+    }
+
+    fn head_ref(&self) -> Option<&str> {
+        self.merge_group.get("head_ref")?.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebHook;
+
+    #[test]
+    fn test_merge_group_event_helpers() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/merge_group_checks_requested.json"
+        );
+        let merge_group = WebHook::<MergeGroupEvent>::from_json_file(path)
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(merge_group.action(), &MergeGroupAction::ChecksRequested);
+        assert_eq!(
+            merge_group.head_sha(),
+            Some("4c9f7b1e2d3a5f6e7b8c9d0a1b2c3d4e5f6a7b8c")
+        );
+        assert_eq!(
+            merge_group.base_sha(),
+            Some("ec26c3e57ca3a959ca5aad62de7213c562f8c821")
+        );
+        assert!(merge_group
+            .head_ref()
+            .is_some_and(|head_ref| head_ref.starts_with("refs/heads/gh-readonly-queue/main/")));
+    }
+}
@@ -2,20 +2,196 @@
 //!
 //! This module contains the various payloads that GitHub sends to the webhook
 
+/// Generate a string enum with an `Other` variant for unknown values
+macro_rules! string_enum {
+    ($(#[$meta:meta])* $enum:ident { $($(#[$doc:meta])* $variant:ident => $name:literal $(| $alias:literal)*,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum $enum {
+            $(
+                $(#[$doc])*
+                $variant,
+            )*
+            /// Unknown value
+            Other(String),
+        }
+
+        impl $enum {
+            /// Get the GitHub name of the value
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($enum::$variant => $name,)*
+                    $enum::Other(value) => value,
+                }
+            }
+        }
+
+        impl std::str::FromStr for $enum {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $($name $(| $alias)* => $enum::$variant,)*
+                    _ => $enum::Other(s.to_string()),
+                })
+            }
+        }
+
+        impl From<String> for $enum {
+            fn from(value: String) -> Self {
+                match value.parse() {
+                    Ok(value) => value,
+                    Err(e) => match e {},
+                }
+            }
+        }
+
+        impl From<$enum> for String {
+            fn from(value: $enum) -> Self {
+                value.as_str().to_string()
+            }
+        }
+
+        impl std::fmt::Display for $enum {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+    };
+}
+
+/// Read a (nested) string field from a raw payload object
+///
+/// Octocrab leaves many of the nested webhook objects (alerts, check runs,
+/// releases, teams, ...) as `serde_json::Value`, so the helpers read the
+/// fields GitHub documents directly from the payload.
+fn payload_field<T: std::str::FromStr>(value: &serde_json::Value, path: &[&str]) -> Option<T> {
+    path.iter()
+        .try_fold(value, |value, name| value.get(name))?
+        .as_str()?
+        .parse()
+        .ok()
+}
+
+/// Convert an Octocrab enum to one of ours using its serialized name
+fn octocrab_enum<T: serde::Serialize, U: From<String>>(value: &T) -> U {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .unwrap_or_default()
+        .into()
+}
+
+pub mod alerts;
+pub mod checks;
+pub mod deployment;
+pub mod discussion;
+pub mod installation;
+pub mod issues;
+pub mod kind;
+pub mod merge_group;
 pub mod payloads;
+pub mod ping;
+pub mod pull_request;
+pub mod push;
+pub mod release;
+pub mod repository;
+pub mod star;
+pub mod team;
+pub mod typed;
+pub mod wiki;
+
+pub use alerts::{
+    AlertEventExt, AlertState, SecretLocation, SecretLocationKind,
+    SecretScanningAlertLocationEventExt, Severity,
+};
+pub use checks::{
+    CheckConclusion, CheckRunEventExt, CheckRunStatus, CheckSuiteAction, CheckSuiteEventExt,
+};
+pub use deployment::{DeploymentState, DeploymentStatusEventExt};
+pub use discussion::{DiscussionAction, DiscussionCommentEventExt, DiscussionEventExt};
+pub use installation::{InstallationAction, InstallationEventExt};
+pub use issues::IssuesEventExt;
+pub use kind::EventKind;
+pub use merge_group::{MergeGroupAction, MergeGroupEventExt};
+pub use ping::PingEventExt;
+pub use pull_request::{PullRequestEventExt, PullRequestReviewEventExt, ReviewState};
+pub use push::{ChangedFiles, PushEventExt};
+pub use release::{ReleaseAction, ReleaseEventExt};
+pub use repository::{
+    OrganizationAction, OrganizationEventExt, RepositoryAction, RepositoryChangesExt,
+    RepositoryEventExt,
+};
+pub use star::{StarAction, StarEventExt, WatchAction, WatchEventExt};
+pub use team::{
+    MemberAction, MemberEventExt, MembershipAction, MembershipEventExt, MembershipScope,
+    TeamAction, TeamAddEventExt, TeamEventExt,
+};
+pub use typed::EventPayload;
+pub use wiki::{
+    CommitCommentAction, CommitCommentEventExt, GollumEventExt, WikiPage, WikiPageAction,
+};
 
 /// A wrapper around a webhook payload.
+///
+/// Contains the payload and the installation ID. The sender of the event and
+/// the raw body (only if retained, see `OctoAppState::raw_body` for Rocket)
+/// are available using `sender()` and `raw_body()`. Use `WebHook::new` to
+/// build one, the extra fields are private.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct WebHook<T>(pub T, pub u64);
+pub struct WebHook<T>(pub T, pub u64, Option<Sender>, Option<std::sync::Arc<str>>);
+
+/// The sender of a webhook event
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct Sender {
+    /// The login of the sender (`my-app[bot]` for GitHub Apps)
+    pub login: String,
+    /// The user ID of the sender
+    pub id: u64,
+    /// The type of the sender (`User`, `Bot` or `Organization`)
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+/// The GitHub Enterprise Cloud enterprise of a webhook event
+///
+/// Only sent for repositories and organizations owned by an enterprise.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Enterprise {
+    /// The enterprise ID
+    pub id: u64,
+    /// The slug of the enterprise (used in the enterprise URL)
+    pub slug: String,
+    /// The name of the enterprise
+    pub name: String,
+    /// The URL of the enterprise on GitHub
+    pub html_url: Option<String>,
+}
 
 impl<T> WebHook<T> {
+    /// Create a new WebHook from the payload and the installation ID
+    ///
+    /// # Example
+    /// ```rust
+    /// # use octoapp::WebHook;
+    /// let webhook = WebHook::new("Hello, world!".to_string(), 12345);
+    /// # assert_eq!(webhook.installation(), 12345);
+    /// # assert!(webhook.sender().is_none());
+    /// ```
+    pub fn new(payload: T, installation: u64) -> Self {
+        Self(payload, installation, None, None)
+    }
+
     /// Consumes the wrapper and returns the inner payload.
     ///
     /// # Example
     /// ```rust
     /// # use octoapp::WebHook;
     /// let string = "Hello, world!".to_string();
-    /// let webhook = WebHook(string, 0);
+    /// let webhook = WebHook::new(string, 0);
     /// let inner = webhook.into_inner();
     /// # assert_eq!(inner, "Hello, world!");
     /// ```
@@ -30,7 +206,7 @@ impl<T> WebHook<T> {
     /// ```rust
     /// # use octoapp::WebHook;
     /// let string = "Hello, world!".to_string();
-    /// let webhook = WebHook(string, 12345);
+    /// let webhook = WebHook::new(string, 12345);
     /// let inst = webhook.installation();
     /// # assert_eq!(inst, 12345);
     /// ```
@@ -38,17 +214,463 @@ impl<T> WebHook<T> {
         self.1
     }
 
-    /// Get a reference to the Installation from the WebHook Installation
-    #[cfg(feature = "rocket")]
+    /// Get the Sender of the WebHook (if present in the payload)
+    pub fn sender(&self) -> Option<&Sender> {
+        self.2.as_ref()
+    }
+
+    /// Set the Sender of the WebHook
+    pub fn with_sender(mut self, sender: Sender) -> Self {
+        self.2 = Some(sender);
+        self
+    }
+
+    /// Get the raw body of the WebHook (if retained)
+    ///
+    /// This is the exact bytes the signature was verified against, useful to
+    /// compute your own hash or forward the payload elsewhere.
+    pub fn raw_body(&self) -> Option<&[u8]> {
+        self.3.as_deref().map(str::as_bytes)
+    }
+
+    /// Retain the raw body on the WebHook
+    pub fn with_raw_body(mut self, body: impl Into<std::sync::Arc<str>>) -> Self {
+        self.3 = Some(body.into());
+        self
+    }
+
+    /// Get the type of the Sender (`User`, `Bot` or `Organization`)
+    pub fn sender_type(&self) -> Option<&str> {
+        self.sender().map(|sender| sender.kind.as_str())
+    }
+
+    /// Check if the WebHook was triggered by a bot
+    ///
+    /// This is the recommended way to prevent webhook loops, for example an
+    /// app commenting on an issue which then triggers an `issue_comment` event
+    /// the app handles again.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use octoapp::events::{Sender, WebHook};
+    /// let sender = Sender {
+    ///     login: "my-app[bot]".to_string(),
+    ///     id: 12345,
+    ///     kind: "Bot".to_string(),
+    /// };
+    /// let webhook = WebHook::new((), 0).with_sender(sender);
+    /// if webhook.is_bot() {
+    ///     // Ignore events caused by bots
+    /// }
+    /// # assert!(webhook.is_bot());
+    /// # assert!(webhook.triggered_by_app("my-app"));
+    /// ```
+    pub fn is_bot(&self) -> bool {
+        self.sender_type() == Some("Bot")
+    }
+
+    /// Check if the WebHook was triggered by the app with the given slug
+    ///
+    /// GitHub Apps act as a bot user named `<app-slug>[bot]` which has a
+    /// different ID than the app itself, so the slug is used to match it.
+    pub fn triggered_by_app(&self, app_slug: &str) -> bool {
+        match self.sender() {
+            Some(sender) if sender.kind == "Bot" => sender.login == format!("{}[bot]", app_slug),
+            _ => false,
+        }
+    }
+
+    /// Get an Octocrab instance scoped to the installation of the WebHook
+    ///
+    /// Returns an `OctocrabInstallationError` if the payload has no installation.
+    /// With Rocket, pass the config from the state (`event.octocrab(&state.config)`).
+    ///
+    /// The client is cached per installation by the config, see
+    /// `OctoAppConfig::octocrab_by_installation`.
     pub async fn octocrab(
         &self,
-        appstate: &rocket::State<crate::ghrocket::OctoAppState>,
+        config: &crate::OctoAppConfig,
     ) -> Result<octocrab::Octocrab, crate::OctoAppError> {
         let id = self.installation();
         if id == 0 {
             return Err(crate::OctoAppError::OctocrabInstallationError(id));
         }
-        appstate.config.octocrab_by_installation(id).await
+        config.octocrab_by_installation(id).await
+    }
+
+    /// List the repositories accessible to the installation of the WebHook
+    ///
+    /// Returns an `OctocrabInstallationError` if the payload has no installation.
+    pub async fn installation_repositories(
+        &self,
+        config: &crate::OctoAppConfig,
+    ) -> Result<Vec<octocrab::models::Repository>, crate::OctoAppError> {
+        let id = self.installation();
+        if id == 0 {
+            return Err(crate::OctoAppError::OctocrabInstallationError(id));
+        }
+        config.installation_repositories(id).await
+    }
+
+    /// Get an Octocrab instance authenticated as the app (JWT)
+    ///
+    /// This is for app level endpoints, use `octocrab()` for the installation.
+    pub fn octocrab_app(
+        &self,
+        config: &crate::OctoAppConfig,
+    ) -> Result<octocrab::Octocrab, crate::OctoAppError> {
+        config.octocrab_app()
+    }
+}
+
+/// Deserialize a WebHook from a string to extract the installation ID and sender
+#[derive(serde::Deserialize)]
+#[non_exhaustive]
+struct ReqBlob {
+    installation: Option<InsBlob>,
+    /// Kept as raw JSON so a malformed sender doesn't drop the installation ID
+    sender: Option<serde_json::Value>,
+}
+
+/// Deserialize a WebHook from a string to extract the installation ID
+#[derive(serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+struct InsBlob {
+    id: u64,
+}
+
+impl ReqBlob {
+    /// Extract the installation ID and sender from a JSON payload
+    fn extract(body: &str) -> (u64, Option<Sender>) {
+        match serde_json::from_str::<ReqBlob>(body) {
+            Ok(blob) => (
+                blob.installation.map(|i| i.id).unwrap_or(0),
+                blob.sender.and_then(|s| serde_json::from_value(s).ok()),
+            ),
+            Err(_) => (0, None),
+        }
+    }
+}
+
+/// The high signal fields of a payload (see `webhook_span`)
+#[derive(serde::Deserialize, Default)]
+struct TraceBlob {
+    action: Option<String>,
+    repository: Option<RepositoryBlob>,
+    sender: Option<LoginBlob>,
+    number: Option<u64>,
+    issue: Option<NumberBlob>,
+    pull_request: Option<NumberBlob>,
+}
+
+#[derive(serde::Deserialize)]
+struct RepositoryBlob {
+    full_name: String,
+}
+
+#[derive(serde::Deserialize)]
+struct LoginBlob {
+    login: String,
+}
+
+#[derive(serde::Deserialize)]
+struct NumberBlob {
+    number: u64,
+}
+
+/// Create a `webhook` span with the high signal fields of the payload
+///
+/// Only the event, delivery, repository, action, sender and the issue / pull
+/// request number are recorded, never the body or secrets.
+pub(crate) fn webhook_span(
+    event: Option<&str>,
+    delivery: Option<&str>,
+    body: &str,
+) -> tracing::Span {
+    let blob: TraceBlob = serde_json::from_str(body).unwrap_or_default();
+    let span = tracing::info_span!(
+        "webhook",
+        event = event.unwrap_or("unknown"),
+        delivery = delivery.unwrap_or("unknown"),
+        repository = tracing::field::Empty,
+        action = tracing::field::Empty,
+        sender = tracing::field::Empty,
+        number = tracing::field::Empty,
+    );
+    if let Some(repository) = &blob.repository {
+        span.record("repository", repository.full_name.as_str());
+    }
+    if let Some(action) = &blob.action {
+        span.record("action", action.as_str());
+    }
+    if let Some(sender) = &blob.sender {
+        span.record("sender", sender.login.as_str());
+    }
+    let number = blob
+        .number
+        .or(blob.issue.map(|issue| issue.number))
+        .or(blob.pull_request.map(|pull_request| pull_request.number));
+    if let Some(number) = number {
+        span.record("number", number);
+    }
+    span
+}
+
+/// Log the `zen` and `hook_id` of a `ping` event
+///
+/// GitHub sends a `ping` when the webhook is created and expects a `2xx`
+/// response, the integrations acknowledge it even if no route handles it.
+pub(crate) fn log_ping(body: &str) {
+    let ping: serde_json::Value = serde_json::from_str(body).unwrap_or_default();
+    tracing::info!(
+        "Received ping for hook {}: {}",
+        ping.get("hook_id")
+            .and_then(|id| id.as_u64())
+            .unwrap_or_default(),
+        ping.get("zen")
+            .and_then(|zen| zen.as_str())
+            .unwrap_or_default()
+    );
+}
+
+/// Check if the raw payload is of a draft pull request (`pull_request.draft`)
+pub(crate) fn is_draft_pull_request(body: &str) -> bool {
+    #[derive(serde::Deserialize)]
+    struct DraftBlob {
+        pull_request: Option<PullRequestBlob>,
+    }
+    #[derive(serde::Deserialize)]
+    struct PullRequestBlob {
+        #[serde(default)]
+        draft: Option<bool>,
+    }
+
+    serde_json::from_str::<DraftBlob>(body)
+        .ok()
+        .and_then(|blob| blob.pull_request?.draft)
+        .unwrap_or(false)
+}
+
+/// Extract the installation ID from a webhook payload
+///
+/// The payloads include the installation the event was delivered for as
+/// `installation.id`, for `installation` events this is the installation the
+/// event is about. Events which aren't scoped to an installation (for example
+/// `marketplace_purchase` or `sponsorship`) return `0`.
+///
+/// This is used by all the integrations to populate `WebHook::installation()`.
+///
+/// # Example
+/// ```rust
+/// # use octoapp::events::extract_installation_id;
+/// let json = r#"{"action": "created", "installation": {"id": 12345}}"#;
+/// assert_eq!(extract_installation_id(json), 12345);
+/// ```
+pub fn extract_installation_id(body: &str) -> u64 {
+    ReqBlob::extract(body).0
+}
+
+/// The serialized WebHook, the payload with the installation and sender
+#[derive(serde::Serialize)]
+struct Envelope<'a, T> {
+    #[serde(flatten)]
+    payload: &'a T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installation: Option<InsBlob>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sender: Option<&'a Sender>,
+}
+
+/// Serialize a WebHook back to a webhook payload
+///
+/// The installation ID and sender are added to the payload (as GitHub sends
+/// them) so it can be parsed again using `WebHook::from_json_str`. The payload
+/// must serialize to a JSON object.
+impl<T: serde::Serialize> serde::Serialize for WebHook<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Envelope {
+            payload: &self.0,
+            installation: (self.1 != 0).then_some(InsBlob { id: self.1 }),
+            sender: self.sender(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<T: serde::Serialize> WebHook<T> {
+    /// Serialize the WebHook to a JSON string
+    ///
+    /// This is useful for forwarding parsed events to a queue. The output
+    /// includes the installation ID and sender and roundtrips through
+    /// `WebHook::from_json_str`, fields not modeled by the payload structs
+    /// are not included (use `raw_body()` to forward the exact payload).
+    pub fn to_json(&self) -> Result<String, crate::OctoAppError> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// Extract the JSON payload from a form encoded webhook body
+///
+/// GitHub sends the JSON payload in the url encoded `payload` field when the
+/// webhook content type is `application/x-www-form-urlencoded`. The signature
+/// covers the whole (encoded) body, so verify it before decoding.
+///
+/// # Example
+/// ```rust
+/// # use octoapp::events::form_payload;
+/// let body = "payload=%7B%22zen%22%3A+%22Design+for+failure.%22%7D";
+/// assert_eq!(form_payload(body).unwrap(), r#"{"zen": "Design for failure."}"#);
+/// ```
+pub fn form_payload(body: &str) -> Result<String, crate::OctoAppError> {
+    let payload = body
+        .split('&')
+        .find_map(|field| field.strip_prefix("payload="))
+        .ok_or(crate::OctoAppError::InvalidFormPayload(
+            "missing `payload` field".to_string(),
+        ))?;
+
+    let mut bytes = payload.bytes();
+    let mut decoded: Vec<u8> = Vec::with_capacity(payload.len());
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let hex = [bytes.next().unwrap_or(0), bytes.next().unwrap_or(0)];
+                let byte = hex::decode(hex).map_err(|_| {
+                    crate::OctoAppError::InvalidFormPayload("invalid url encoding".to_string())
+                })?;
+                decoded.extend(byte);
+            }
+            byte => decoded.push(byte),
+        }
+    }
+    String::from_utf8(decoded).map_err(|e| crate::OctoAppError::InvalidFormPayload(e.to_string()))
+}
+
+impl<'r, T: serde::Deserialize<'r>> WebHook<T> {
+    /// Deserialize a WebHook from a JSON string
+    ///
+    /// The installation ID is extracted from the payload the same way as the
+    /// web framework integrations do. This is useful for building `WebHook`
+    /// instances from recorded payloads in tests.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use octoapp::prelude::*;
+    /// let json = r#"{"zen": "Design for failure.", "hook_id": 1, "installation": {"id": 12345}}"#;
+    /// let webhook = WebHook::<PingEvent>::from_json_str(json).unwrap();
+    /// # assert_eq!(webhook.installation(), 12345);
+    /// ```
+    pub fn from_json_str(s: &'r str) -> Result<Self, crate::OctoAppError> {
+        // TODO: This is a little hacky, but it works 🤷
+        // We do deserialization in two steps here to extract the
+        // installation ID / sender and the payload.
+
+        let (id, sender): (u64, Option<Sender>) = ReqBlob::extract(s);
+
+        // Track the path so errors point at the field which failed
+        let mut deserializer = serde_json::Deserializer::from_str(s);
+        let value = serde_path_to_error::deserialize(&mut deserializer).map_err(|e| {
+            crate::OctoAppError::PayloadError {
+                path: e.path().to_string(),
+                source: e.into_inner(),
+            }
+        })?;
+        deserializer.end()?;
+
+        Ok(WebHook(value, id, sender, None))
+    }
+}
+
+impl WebHook<Event> {
+    /// Deserialize a WebHook from a JSON string of a known event kind
+    ///
+    /// Unlike `from_json_str` the payload is only deserialized into the
+    /// variant of the kind (`X-GitHub-Event` header), see `Event::from_value`.
+    pub fn from_event_str(kind: EventKind, s: &str) -> Result<Self, crate::OctoAppError> {
+        let (id, sender): (u64, Option<Sender>) = ReqBlob::extract(s);
+        let event = Event::from_value(kind, serde_json::from_str(s)?)?;
+        Ok(WebHook(event, id, sender, None))
+    }
+}
+
+/// Parse a webhook without verifying the signature
+///
+/// The payload is deserialized into the `Event` of the event name (the
+/// `X-GitHub-Event` header) and the installation / sender are extracted,
+/// without an `OctoAppConfig`. This is for apps behind a gateway which
+/// already verified the signature, **never** use it on unverified requests.
+///
+/// ```rust
+/// # use octoapp::prelude::*;
+/// let body = r#"{"zen": "Design for failure.", "hook_id": 1, "installation": {"id": 12345}}"#;
+/// let webhook = octoapp::parse_event("ping", body).unwrap();
+/// assert_eq!(webhook.0.kind(), EventKind::Ping);
+/// assert_eq!(webhook.installation(), 12345);
+/// ```
+pub fn parse_event(event_name: &str, body: &str) -> Result<WebHook<Event>, crate::OctoAppError> {
+    let kind: EventKind = event_name.parse()?;
+    if body.trim().is_empty() {
+        return Err(crate::OctoAppError::EmptyBody);
+    }
+    WebHook::from_event_str(kind, body)
+}
+
+/// Untyped payloads
+///
+/// A `WebHook<serde_json::Value>` keeps the whole payload, which is an escape
+/// hatch for logging or routing on fields this crate (or Octocrab) doesn't
+/// model yet. It can be typed afterwards without re-parsing the raw body.
+///
+/// ```rust
+/// # use octoapp::prelude::*;
+/// let json = r#"{"action": "opened", "installation": {"id": 12345}}"#;
+/// let webhook = WebHook::<serde_json::Value>::from_json_str(json).unwrap();
+/// assert_eq!(webhook.field(&["action"]).and_then(|a| a.as_str()), Some("opened"));
+/// ```
+impl WebHook<serde_json::Value> {
+    /// Get the JSON payload
+    pub fn payload_value(&self) -> &serde_json::Value {
+        &self.0
+    }
+
+    /// Get a (nested) field of the payload (`&["pull_request", "head", "ref"]`)
+    pub fn field(&self, path: &[&str]) -> Option<&serde_json::Value> {
+        path.iter().try_fold(&self.0, |value, name| value.get(name))
+    }
+
+    /// Deserialize the payload, keeping the installation, sender and raw body
+    pub fn into_payload<T: serde::de::DeserializeOwned>(
+        self,
+    ) -> Result<WebHook<T>, crate::OctoAppError> {
+        let payload = serde_path_to_error::deserialize(self.0).map_err(|e| {
+            crate::OctoAppError::PayloadError {
+                path: e.path().to_string(),
+                source: e.into_inner(),
+            }
+        })?;
+        Ok(WebHook(payload, self.1, self.2, self.3))
+    }
+
+    /// Deserialize the payload into the `Event` of a known kind
+    pub fn into_event(self, kind: EventKind) -> Result<WebHook<Event>, crate::OctoAppError> {
+        let event = Event::from_value(kind, self.0)?;
+        Ok(WebHook(event, self.1, self.2, self.3))
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> WebHook<T> {
+    /// Deserialize a WebHook from a form encoded body (`payload=`)
+    ///
+    /// See `form_payload` for the delivery mode.
+    pub fn from_form_str(s: &str) -> Result<Self, crate::OctoAppError> {
+        WebHook::from_json_str(&form_payload(s)?)
+    }
+
+    /// Deserialize a WebHook from a JSON file (recorded payload)
+    pub fn from_json_file(path: impl AsRef<std::path::Path>) -> Result<Self, crate::OctoAppError> {
+        let data = std::fs::read_to_string(path)?;
+        WebHook::from_json_str(&data)
     }
 }
 
@@ -185,3 +807,328 @@ pub enum Event {
     /// Workflow Job Event
     WorkflowJob(payloads::WorkflowJobEvent),
 }
+
+impl Event {
+    /// Get the enterprise of the event (GitHub Enterprise Cloud)
+    ///
+    /// Returns `None` for events outside an enterprise and the events which
+    /// never include one (for example `ping` or `check_run`).
+    pub fn enterprise(&self) -> Option<Enterprise> {
+        /// Match the variants with an `enterprise` field
+        macro_rules! enterprise {
+            ($($kind:ident),* $(,)?) => {
+                match self {
+                    $(Event::$kind(e) => e.enterprise.as_ref(),)*
+                    _ => None,
+                }
+            };
+        }
+
+        let enterprise = enterprise!(
+            BranchProtectionRule,
+            CheckSuite,
+            CodeScanningAlert,
+            Create,
+            Delete,
+            DependabotAlert,
+            DeployKey,
+            Deployment,
+            DeploymentStatus,
+            Discussion,
+            DiscussionComment,
+            Fork,
+            GithubAppAuthorization,
+            Gollum,
+            Installation,
+            InstallationRepositories,
+            InstallationTarget,
+            IssueComment,
+            Issues,
+            Label,
+            Member,
+            Membership,
+            Meta,
+            Milestone,
+            OrgBlock,
+            Organization,
+            Package,
+            PageBuild,
+            ProjectCard,
+            ProjectColumn,
+            Public,
+            PullRequest,
+            PullRequestReviewComment,
+            Push,
+            RegistryPackage,
+            Release,
+            Repository,
+            RepositoryAdvisory,
+            RepositoryImport,
+            RepositoryVulnerabilityAlert,
+            SecretScanningAlert,
+            SecurityAdvisory,
+            SecurityAndAnalysis,
+            Sponsorship,
+            Star,
+            Status,
+            Team,
+            TeamAdd,
+            Watch,
+            WorkflowDispatch,
+            WorkflowJob,
+        )?;
+        serde_json::from_value(enterprise.clone()).ok()
+    }
+
+    /// Get the `changes` of the event describing the previous values
+    ///
+    /// This is only populated for `edited` (and similar, like `renamed` or
+    /// `transferred`) actions, for example the previous `title` / `body` of an
+    /// issue as `{"title": {"from": "Old title"}}`. Returns `None` for events
+    /// without changes.
+    pub fn changes(&self) -> Option<serde_json::Value> {
+        /// Convert typed changes to a JSON value
+        fn value<T: serde::Serialize>(changes: Option<&T>) -> Option<serde_json::Value> {
+            changes.and_then(|changes| serde_json::to_value(changes).ok())
+        }
+
+        match self {
+            Event::BranchProtectionRule(e) => value(e.changes.as_ref()),
+            Event::DiscussionComment(e) => e.changes.clone(),
+            Event::Discussion(e) => e.changes.clone(),
+            Event::InstallationTarget(e) => Some(e.changes.clone()),
+            Event::IssueComment(e) => value(e.changes.as_ref()),
+            Event::Issues(e) => value(e.changes.as_ref()),
+            Event::Label(e) => e.changes.clone(),
+            Event::Member(e) => e.changes.clone(),
+            Event::Milestone(e) => e.changes.clone(),
+            Event::Organization(e) => e.changes.clone(),
+            Event::ProjectCard(e) => e.changes.clone(),
+            Event::ProjectColumn(e) => e.changes.clone(),
+            Event::ProjectsV2Item(e) => e.changes.clone(),
+            Event::ProjectsV2(e) => e.changes.clone(),
+            Event::PullRequestReviewComment(e) => value(e.changes.as_ref()),
+            Event::PullRequestReview(e) => value(e.changes.as_ref()),
+            Event::Release(e) => e.changes.clone(),
+            Event::Repository(e) => value(e.changes.as_ref()),
+            Event::SecurityAndAnalysis(e) => Some(e.changes.clone()),
+            Event::Sponsorship(e) => e.changes.clone(),
+            Event::Team(e) => e.changes.clone(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> std::path::PathBuf {
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join(name)
+    }
+
+    #[test]
+    fn test_webhook_from_json_file() {
+        let webhook = WebHook::<payloads::PingEvent>::from_json_file(fixture("ping.json")).unwrap();
+        assert_eq!(webhook.installation(), 0);
+        assert_eq!(webhook.sender_type(), Some("User"));
+        assert!(!webhook.is_bot());
+        assert_eq!(webhook.0.zen, Some("Design for failure.".to_string()));
+
+        let webhook =
+            WebHook::<payloads::IssuesEvent>::from_json_file(fixture("issues_opened.json"))
+                .unwrap();
+        assert_eq!(webhook.installation(), 52345678);
+        assert_eq!(webhook.0.issue.number, 42);
+    }
+
+    #[test]
+    fn test_extract_installation_id() {
+        let data = std::fs::read_to_string(fixture("issues_opened.json")).unwrap();
+        assert_eq!(extract_installation_id(&data), 52345678);
+
+        let data = r#"{"action": "deleted", "installation": {"id": 42, "app_id": 1}}"#;
+        assert_eq!(extract_installation_id(data), 42);
+
+        // A malformed sender must not drop the installation ID
+        let data = r#"{"installation": {"id": 42}, "sender": {"login": 1}}"#;
+        assert_eq!(ReqBlob::extract(data), (42, None));
+
+        let data = r#"{"action": "created", "sponsorship": {}}"#;
+        assert_eq!(extract_installation_id(data), 0);
+        assert_eq!(extract_installation_id("not json"), 0);
+    }
+
+    #[test]
+    fn test_webhook_payload_error() {
+        let json = r#"{"action": "opened", "issue": {"number": "not a number"}}"#;
+        match WebHook::<payloads::IssuesEvent>::from_json_str(json) {
+            Err(crate::OctoAppError::PayloadError { path, source }) => {
+                assert_eq!(path, "issue.number");
+                assert_eq!(source.line(), 1);
+            }
+            other => panic!("Expected PayloadError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_is_draft_pull_request() {
+        assert!(is_draft_pull_request(
+            r#"{"action": "opened", "pull_request": {"draft": true}}"#
+        ));
+        assert!(!is_draft_pull_request(
+            r#"{"action": "opened", "pull_request": {"draft": false}}"#
+        ));
+        assert!(!is_draft_pull_request(
+            r#"{"action": "opened", "issue": {}}"#
+        ));
+        assert!(!is_draft_pull_request("not json"));
+    }
+
+    #[test]
+    fn test_parse_event() {
+        let body = std::fs::read_to_string(fixture("issues_opened.json")).unwrap();
+        let webhook = parse_event("issues", &body).unwrap();
+        assert_eq!(webhook.0.kind(), EventKind::Issues);
+        assert_eq!(webhook.installation(), 52345678);
+
+        assert!(matches!(
+            parse_event("unknown", &body),
+            Err(crate::OctoAppError::UnknownEvent(_))
+        ));
+        assert!(matches!(
+            parse_event("issues", " "),
+            Err(crate::OctoAppError::EmptyBody)
+        ));
+        assert!(parse_event("push", &body).is_err());
+    }
+
+    #[test]
+    fn test_event_enterprise() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(fixture("issues_opened.json")).unwrap())
+                .unwrap();
+        let event = Event::from_value(EventKind::Issues, value.clone()).unwrap();
+        assert_eq!(event.enterprise(), None);
+
+        value["enterprise"] = serde_json::json!({
+            "id": 1234,
+            "slug": "octo-enterprise",
+            "name": "Octo Enterprise",
+            "node_id": "E_kgDNBNI",
+            "html_url": "https://github.com/enterprises/octo-enterprise",
+            "created_at": "2024-01-01T00:00:00Z"
+        });
+        let event = Event::from_value(EventKind::Issues, value).unwrap();
+        let enterprise = event.enterprise().unwrap();
+        assert_eq!(enterprise.id, 1234);
+        assert_eq!(enterprise.slug, "octo-enterprise");
+        assert_eq!(enterprise.name, "Octo Enterprise");
+    }
+
+    #[test]
+    fn test_webhook_payload_value() {
+        let webhook =
+            WebHook::<serde_json::Value>::from_json_file(fixture("issues_opened.json")).unwrap();
+        assert_eq!(webhook.installation(), 52345678);
+        assert_eq!(
+            webhook.field(&["issue", "number"]),
+            Some(&serde_json::json!(42))
+        );
+        assert!(webhook.payload_value().get("action").is_some());
+
+        let issues = webhook
+            .clone()
+            .into_payload::<payloads::IssuesEvent>()
+            .unwrap();
+        assert_eq!(issues.installation(), 52345678);
+        assert_eq!(issues.0.issue.number, 42);
+
+        let event = webhook.clone().into_event(EventKind::Issues).unwrap();
+        assert_eq!(event.0.kind(), EventKind::Issues);
+
+        assert!(matches!(
+            webhook.into_payload::<payloads::PushEvent>(),
+            Err(crate::OctoAppError::PayloadError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_webhook_from_form_str() {
+        let data = std::fs::read_to_string(fixture("issues_opened.json")).unwrap();
+        let form = format!(
+            "payload={}",
+            data.bytes()
+                .map(|b| match b {
+                    b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => (b as char).to_string(),
+                    b' ' => "+".to_string(),
+                    b => format!("%{:02X}", b),
+                })
+                .collect::<String>()
+        );
+
+        let json = WebHook::<payloads::IssuesEvent>::from_json_str(&data).unwrap();
+        let form = WebHook::<payloads::IssuesEvent>::from_form_str(&form).unwrap();
+        assert_eq!(form, json);
+
+        assert_eq!(
+            form_payload("payload=%F0%9F%A6%80+%E2%9C%93").unwrap(),
+            "🦀 ✓"
+        );
+        for body in ["other=1", "payload=%ZZ", "payload=%FF"] {
+            assert!(matches!(
+                form_payload(body),
+                Err(crate::OctoAppError::InvalidFormPayload(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_webhook_from_json_str_event() {
+        let data = std::fs::read_to_string(fixture("issues_opened.json")).unwrap();
+        let webhook = WebHook::<Event>::from_json_str(&data).unwrap();
+        assert_eq!(webhook.installation(), 52345678);
+        assert!(matches!(webhook.into_inner(), Event::Issues(_)));
+    }
+
+    #[test]
+    fn test_event_changes() {
+        let data = std::fs::read_to_string(fixture("issues_opened.json")).unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&data).unwrap();
+        value["action"] = "edited".into();
+        value["changes"] = serde_json::json!({"title": {"from": "Old title"}});
+
+        let issues: payloads::IssuesEvent = serde_json::from_value(value).unwrap();
+        let changes = Event::Issues(issues).changes().unwrap();
+        assert_eq!(changes["title"]["from"], "Old title");
+
+        let ping = WebHook::<Event>::from_json_file(fixture("ping.json")).unwrap();
+        assert_eq!(ping.into_inner().changes(), None);
+    }
+
+    fn assert_roundtrip<T>(name: &str)
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+    {
+        let webhook = WebHook::<T>::from_json_file(fixture(name)).unwrap();
+        let json = webhook.to_json().unwrap();
+        let roundtrip = WebHook::<T>::from_json_str(&json).unwrap();
+        assert_eq!(roundtrip, webhook, "{}", name);
+    }
+
+    #[test]
+    fn test_webhook_serialize_roundtrip() {
+        assert_roundtrip::<payloads::PingEvent>("ping.json");
+        assert_roundtrip::<payloads::IssuesEvent>("issues_opened.json");
+        assert_roundtrip::<payloads::IssuesEvent>("issues_labeled.json");
+        assert_roundtrip::<payloads::PushEvent>("push.json");
+        assert_roundtrip::<payloads::ReleaseEvent>("release_published.json");
+
+        let webhook = WebHook::<Event>::from_json_file(fixture("issues_opened.json")).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&webhook.to_json().unwrap()).unwrap();
+        assert_eq!(value["installation"]["id"], 52345678);
+        assert_eq!(value["issue"]["number"], 42);
+    }
+}
@@ -0,0 +1,83 @@
+//! # Ping Event Helpers
+//!
+//! GitHub sends a `ping` when the webhook is created, which includes the
+//! webhook configuration. Apps can check it is subscribed to the events they
+//! expect.
+
+use octocrab::models::hooks::Config;
+
+use super::{payloads::PingEvent, EventKind};
+
+/// Helpers for the Ping Event
+pub trait PingEventExt {
+    /// Get the events the webhook is subscribed to
+    ///
+    /// Events which aren't a known `EventKind` are skipped.
+    fn subscribed_events(&self) -> Vec<EventKind>;
+    /// Get the events of the list the webhook isn't subscribed to
+    fn missing_events(&self, events: &[EventKind]) -> Vec<EventKind>;
+    /// Get the configuration of the webhook (content type, url and SSL)
+    fn hook_config(&self) -> Option<&Config>;
+}
+
+impl PingEventExt for PingEvent {
+    fn subscribed_events(&self) -> Vec<EventKind> {
+        self.hook
+            .iter()
+            .flat_map(|hook| hook.events.iter())
+            .filter_map(|event| {
+                // Octocrab uses the GitHub event names
+                serde_json::to_value(event)
+                    .ok()?
+                    .as_str()?
+                    .parse::<EventKind>()
+                    .ok()
+            })
+            .collect()
+    }
+
+    fn missing_events(&self, events: &[EventKind]) -> Vec<EventKind> {
+        let subscribed = self.subscribed_events();
+        events
+            .iter()
+            .filter(|event| !subscribed.contains(event))
+            .copied()
+            .collect()
+    }
+
+    fn hook_config(&self) -> Option<&Config> {
+        self.hook.as_ref().map(|hook| &hook.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebHook;
+
+    #[test]
+    fn test_ping_subscribed_events() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/ping.json");
+        let ping = WebHook::<PingEvent>::from_json_file(path)
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            ping.subscribed_events(),
+            vec![
+                EventKind::Issues,
+                EventKind::IssueComment,
+                EventKind::PullRequest,
+                EventKind::Push
+            ]
+        );
+        assert_eq!(
+            ping.missing_events(&[EventKind::Push, EventKind::Release]),
+            vec![EventKind::Release]
+        );
+
+        let config = ping.hook_config().unwrap();
+        assert_eq!(config.url, "https://smee.io/octoapp");
+        assert_eq!(config.insecure_ssl.as_deref(), Some("0"));
+    }
+}
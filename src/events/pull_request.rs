@@ -0,0 +1,158 @@
+//! # Pull Request Event Helpers
+
+use octocrab::models::{webhook_events::payload::PullRequestWebhookEventAction, Label};
+
+use super::payloads::{PullRequestEvent, PullRequestReviewEvent};
+
+string_enum! {
+    /// Pull Request Review State
+    ReviewState {
+        /// Approved
+        Approved => "approved",
+        /// Changes Requested
+        ChangesRequested => "changes_requested",
+        /// Commented
+        Commented => "commented",
+        /// Dismissed
+        Dismissed => "dismissed",
+        /// Pending
+        Pending => "pending",
+    }
+}
+
+impl From<&octocrab::models::pulls::ReviewState> for ReviewState {
+    fn from(value: &octocrab::models::pulls::ReviewState) -> Self {
+        // Octocrab serializes the state in upper case (`APPROVED`)
+        serde_json::to_value(value)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_lowercase))
+            .unwrap_or_default()
+            .into()
+    }
+}
+
+/// Helpers for the Pull Request Event
+pub trait PullRequestEventExt {
+    /// Check if the pull request was closed by merging it
+    fn was_merged(&self) -> bool;
+    /// Check if the pull request was closed without being merged
+    fn is_closed_without_merge(&self) -> bool;
+    /// Get the name of the base branch (the branch merged into)
+    fn branch_base(&self) -> &str;
+    /// Get the name of the head branch (the branch with the changes)
+    fn branch_head(&self) -> &str;
+    /// Get the label added to the pull request (`labeled` action)
+    fn added_label(&self) -> Option<&Label>;
+    /// Get the label removed from the pull request (`unlabeled` action)
+    fn removed_label(&self) -> Option<&Label>;
+    /// Check if the pull request currently has the label with the given name
+    fn has_label(&self, name: &str) -> bool;
+    /// Check if the pull request is a draft
+    fn is_draft(&self) -> bool;
+}
+
+impl PullRequestEventExt for PullRequestEvent {
+    fn was_merged(&self) -> bool {
+        matches!(self.action, PullRequestWebhookEventAction::Closed)
+            && self.pull_request.merged_at.is_some()
+    }
+
+    fn is_closed_without_merge(&self) -> bool {
+        matches!(self.action, PullRequestWebhookEventAction::Closed)
+            && self.pull_request.merged_at.is_none()
+    }
+
+    fn branch_base(&self) -> &str {
+        &self.pull_request.base.ref_field
+    }
+
+    fn branch_head(&self) -> &str {
+        &self.pull_request.head.ref_field
+    }
+
+    fn added_label(&self) -> Option<&Label> {
+        match self.action {
+            PullRequestWebhookEventAction::Labeled => self.label.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn removed_label(&self) -> Option<&Label> {
+        match self.action {
+            PullRequestWebhookEventAction::Unlabeled => self.label.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn has_label(&self, name: &str) -> bool {
+        self.pull_request
+            .labels
+            .iter()
+            .flatten()
+            .any(|label| label.name == name)
+    }
+
+    fn is_draft(&self) -> bool {
+        self.pull_request.draft.unwrap_or(false)
+    }
+}
+
+/// Helpers for the Pull Request Review Event
+pub trait PullRequestReviewEventExt {
+    /// Get the state of the review
+    fn state(&self) -> ReviewState;
+    /// Check if the review approved the pull request
+    fn is_approval(&self) -> bool;
+    /// Check if the review requested changes to the pull request
+    fn requests_changes(&self) -> bool;
+    /// Get the login of the reviewer
+    fn reviewer(&self) -> Option<&str>;
+}
+
+impl PullRequestReviewEventExt for PullRequestReviewEvent {
+    fn state(&self) -> ReviewState {
+        self.review
+            .state
+            .as_ref()
+            .map(ReviewState::from)
+            .unwrap_or_else(|| ReviewState::Other(String::new()))
+    }
+
+    fn is_approval(&self) -> bool {
+        self.state() == ReviewState::Approved
+    }
+
+    fn requests_changes(&self) -> bool {
+        self.state() == ReviewState::ChangesRequested
+    }
+
+    fn reviewer(&self) -> Option<&str> {
+        self.review.user.as_ref().map(|user| user.login.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_review_state() {
+        use octocrab::models::pulls::ReviewState as OctocrabReviewState;
+
+        assert_eq!(
+            ReviewState::from(&OctocrabReviewState::Approved),
+            ReviewState::Approved
+        );
+        assert_eq!(
+            ReviewState::from(&OctocrabReviewState::ChangesRequested),
+            ReviewState::ChangesRequested
+        );
+        assert_eq!(
+            ReviewState::from(&OctocrabReviewState::Open),
+            ReviewState::Other("open".to_string())
+        );
+
+        let state: ReviewState = serde_json::from_str(r#""changes_requested""#).unwrap();
+        assert_eq!(state, ReviewState::ChangesRequested);
+    }
+}
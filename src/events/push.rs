@@ -0,0 +1,98 @@
+//! # Push Event Helpers
+
+use std::collections::BTreeSet;
+
+use super::payloads::PushEvent;
+
+/// The files changed across all the commits of a push
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangedFiles {
+    /// Files added by the push
+    pub added: Vec<String>,
+    /// Files modified by the push
+    pub modified: Vec<String>,
+    /// Files removed by the push
+    pub removed: Vec<String>,
+}
+
+/// Helpers for the Push Event
+pub trait PushEventExt {
+    /// Check if the push deleted a branch (`after` is all zeros)
+    fn is_branch_deletion(&self) -> bool;
+    /// Check if the push created a new branch (`before` is all zeros)
+    fn is_new_branch(&self) -> bool;
+    /// Get the branch name (`refs/heads/` stripped) if a branch was pushed
+    fn branch_name(&self) -> Option<&str>;
+    /// Get the tag name (`refs/tags/` stripped) if a tag was pushed
+    fn tag_name(&self) -> Option<&str>;
+    /// Collect the added, modified and removed files across all the commits
+    fn changed_files(&self) -> ChangedFiles;
+}
+
+/// Check if a commit SHA is the null SHA GitHub uses for missing commits
+fn is_null_sha(sha: &str) -> bool {
+    !sha.is_empty() && sha.chars().all(|c| c == '0')
+}
+
+impl PushEventExt for PushEvent {
+    fn is_branch_deletion(&self) -> bool {
+        self.branch_name().is_some() && is_null_sha(&self.after)
+    }
+
+    fn is_new_branch(&self) -> bool {
+        self.branch_name().is_some() && is_null_sha(&self.before)
+    }
+
+    fn branch_name(&self) -> Option<&str> {
+        self.r#ref.strip_prefix("refs/heads/")
+    }
+
+    fn tag_name(&self) -> Option<&str> {
+        self.r#ref.strip_prefix("refs/tags/")
+    }
+
+    fn changed_files(&self) -> ChangedFiles {
+        let mut added = BTreeSet::new();
+        let mut modified = BTreeSet::new();
+        let mut removed = BTreeSet::new();
+
+        for commit in &self.commits {
+            added.extend(commit.added.iter().cloned());
+            modified.extend(commit.modified.iter().cloned());
+            removed.extend(commit.removed.iter().cloned());
+        }
+
+        ChangedFiles {
+            added: added.into_iter().collect(),
+            modified: modified.into_iter().collect(),
+            removed: removed.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebHook;
+
+    #[test]
+    fn test_push_event_helpers() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/push.json");
+        let push = WebHook::<PushEvent>::from_json_file(path)
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(push.branch_name(), Some("feature/smee"));
+        assert_eq!(push.tag_name(), None);
+        assert!(!push.is_new_branch());
+        assert!(!push.is_branch_deletion());
+
+        let files = push.changed_files();
+        assert_eq!(files.added, vec!["src/smee.rs"]);
+        assert_eq!(
+            files.modified,
+            vec!["Cargo.toml", "README.md", "src/lib.rs", "src/smee.rs"]
+        );
+        assert_eq!(files.removed, vec!["docs/old.md"]);
+    }
+}
@@ -0,0 +1,92 @@
+//! # Release Event Helpers
+//!
+//! The tag, assets and draft / prerelease state of a release.
+
+pub use octocrab::models::webhook_events::payload::ReleaseWebhookEventAction as ReleaseAction;
+
+use super::payloads::ReleaseEvent;
+
+/// Helpers for the Release Event
+pub trait ReleaseEventExt {
+    /// Get the action of the event
+    fn action(&self) -> &ReleaseAction;
+    /// Get the tag name of the release
+    fn tag_name(&self) -> Option<&str>;
+    /// Check if the release is a prerelease
+    fn is_prerelease(&self) -> bool;
+    /// Check if the release is a draft
+    fn is_draft(&self) -> bool;
+    /// Get the assets of the release as `(name, download URL)` pairs
+    fn assets(&self) -> Vec<(&str, &str)>;
+}
+
+impl ReleaseEventExt for ReleaseEvent {
+    fn action(&self) -> &ReleaseAction {
+        &self.action
+    }
+
+    fn tag_name(&self) -> Option<&str> {
+        self.release.get("tag_name")?.as_str()
+    }
+
+    fn is_prerelease(&self) -> bool {
+        self.release
+            .get("prerelease")
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false)
+    }
+
+    fn is_draft(&self) -> bool {
+        self.release
+            .get("draft")
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false)
+    }
+
+    fn assets(&self) -> Vec<(&str, &str)> {
+        self.release
+            .get("assets")
+            .and_then(|assets| assets.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|asset| {
+                Some((
+                    asset.get("name")?.as_str()?,
+                    asset.get("browser_download_url")?.as_str()?,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebHook;
+
+    #[test]
+    fn test_release_event_helpers() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/release_published.json"
+        );
+        let release = WebHook::<ReleaseEvent>::from_json_file(path)
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(release.action(), &ReleaseAction::Published);
+        assert_eq!(release.tag_name(), Some("v0.3.0"));
+        assert!(!release.is_prerelease());
+        assert!(!release.is_draft());
+
+        let assets = release.assets();
+        assert_eq!(assets.len(), 2);
+        assert_eq!(
+            assets[0],
+            (
+                "octoapp-replay-x86_64-unknown-linux-gnu.tar.gz",
+                "https://github.com/42ByteLabs/octoapp/releases/download/v0.3.0/octoapp-replay-x86_64-unknown-linux-gnu.tar.gz"
+            )
+        );
+    }
+}
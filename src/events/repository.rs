@@ -0,0 +1,235 @@
+//! # Repository / Organization Event Helpers
+//!
+//! Octocrab types the repository `changes` with a `user` as the previous
+//! owner, so `transferred` events from an organization fail to parse as a
+//! `RepositoryEvent`. `RepositoryChangesExt` is also implemented for the
+//! untyped payload (`WebHook<serde_json::Value>`) to handle those.
+
+use super::{
+    octocrab_enum,
+    payloads::{OrganizationEvent, RepositoryEvent},
+};
+
+string_enum! {
+    /// Repository Event Action
+    RepositoryAction {
+        /// Repository archived
+        Archived => "archived",
+        /// Repository created
+        Created => "created",
+        /// Repository deleted
+        Deleted => "deleted",
+        /// Repository edited (description, homepage, default branch or topics)
+        Edited => "edited",
+        /// Repository made private
+        Privatized => "privatized",
+        /// Repository made public
+        Publicized => "publicized",
+        /// Repository renamed
+        Renamed => "renamed",
+        /// Repository transferred to another owner
+        Transferred => "transferred",
+        /// Repository unarchived
+        Unarchived => "unarchived",
+    }
+}
+
+string_enum! {
+    /// Organization Event Action
+    OrganizationAction {
+        /// Organization deleted
+        Deleted => "deleted",
+        /// Member added to the organization
+        MemberAdded => "member_added",
+        /// Member invited to the organization
+        MemberInvited => "member_invited",
+        /// Member removed from the organization
+        MemberRemoved => "member_removed",
+        /// Organization renamed
+        Renamed => "renamed",
+    }
+}
+
+/// Helpers for the Repository Event
+pub trait RepositoryEventExt {
+    /// Get the action of the event
+    fn action(&self) -> RepositoryAction;
+    /// Check if the visibility of the repository changed (`privatized` or `publicized`)
+    fn is_visibility_change(&self) -> bool;
+    /// Get the previous default branch (`edited`)
+    fn previous_default_branch(&self) -> Option<&str>;
+}
+
+impl RepositoryEventExt for RepositoryEvent {
+    fn action(&self) -> RepositoryAction {
+        octocrab_enum(&self.action)
+    }
+
+    fn is_visibility_change(&self) -> bool {
+        matches!(
+            self.action(),
+            RepositoryAction::Privatized | RepositoryAction::Publicized
+        )
+    }
+
+    fn previous_default_branch(&self) -> Option<&str> {
+        self.changes
+            .as_ref()?
+            .default_branch
+            .as_ref()
+            .map(|branch| branch.from.as_str())
+    }
+}
+
+/// Helpers for the previous name and owner of a repository
+///
+/// Implemented for the typed `RepositoryEvent` and the untyped payload of a
+/// `repository` event.
+pub trait RepositoryChangesExt {
+    /// Get the previous name of the repository (`renamed`)
+    fn previous_name(&self) -> Option<&str>;
+    /// Get the login of the previous owner of the repository (`transferred`)
+    fn previous_owner(&self) -> Option<&str>;
+}
+
+impl RepositoryChangesExt for RepositoryEvent {
+    fn previous_name(&self) -> Option<&str> {
+        self.changes
+            .as_ref()?
+            .repository
+            .as_ref()?
+            .name
+            .as_ref()
+            .map(|name| name.from.as_str())
+    }
+
+    /// Only set for repositories transferred from a user
+    fn previous_owner(&self) -> Option<&str> {
+        self.changes
+            .as_ref()?
+            .owner
+            .as_ref()
+            .map(|owner| owner.from.user.login.as_str())
+    }
+}
+
+impl RepositoryChangesExt for serde_json::Value {
+    fn previous_name(&self) -> Option<&str> {
+        self.pointer("/changes/repository/name/from")?.as_str()
+    }
+
+    fn previous_owner(&self) -> Option<&str> {
+        self.pointer("/changes/owner/from/user/login")
+            .or_else(|| self.pointer("/changes/owner/from/organization/login"))?
+            .as_str()
+    }
+}
+
+/// Helpers for the Organization Event
+pub trait OrganizationEventExt {
+    /// Get the action of the event
+    fn action(&self) -> OrganizationAction;
+    /// Get the previous login of the organization (`renamed`)
+    fn previous_login(&self) -> Option<&str>;
+    /// Get the login of the member (`member_added`, `member_invited` and `member_removed`)
+    fn member_login(&self) -> Option<&str>;
+}
+
+impl OrganizationEventExt for OrganizationEvent {
+    fn action(&self) -> OrganizationAction {
+        octocrab_enum(&self.action)
+    }
+
+    fn previous_login(&self) -> Option<&str> {
+        self.changes.as_ref()?.get("login")?.get("from")?.as_str()
+    }
+
+    fn member_login(&self) -> Option<&str> {
+        self.membership
+            .as_ref()?
+            .get("user")?
+            .get("login")?
+            .as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebHook;
+
+    #[test]
+    fn test_repository_events() {
+        // Transferred from an organization, which Octocrab can't parse
+        let body = r#"{
+            "action": "transferred",
+            "changes": {"owner": {"from": {"organization": {"login": "octo-org"}}}},
+            "installation": {"id": 1}
+        }"#;
+        assert!(WebHook::<RepositoryEvent>::from_json_str(body).is_err());
+        let event = WebHook::<serde_json::Value>::from_json_str(body).unwrap();
+        assert_eq!(event.installation(), 1);
+        assert_eq!(event.0.previous_owner(), Some("octo-org"));
+        assert_eq!(event.0.previous_name(), None);
+
+        // Transferred from a user
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/commit_comment_created.json"
+        );
+        let fixture: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let body = serde_json::json!({
+            "action": "transferred",
+            "changes": {"owner": {"from": {"user": fixture["sender"]}}},
+            "installation": {"id": 1}
+        })
+        .to_string();
+        let event = WebHook::<RepositoryEvent>::from_json_str(&body).unwrap();
+        assert_eq!(event.0.action(), RepositoryAction::Transferred);
+        assert!(!event.0.is_visibility_change());
+        assert_eq!(event.0.previous_owner(), Some("GeekMasher"));
+        let event = WebHook::<serde_json::Value>::from_json_str(&body).unwrap();
+        assert_eq!(event.0.previous_owner(), Some("GeekMasher"));
+
+        let body = r#"{
+            "action": "renamed",
+            "changes": {"repository": {"name": {"from": "octoapp-old"}}},
+            "installation": {"id": 1}
+        }"#;
+        let event = WebHook::<RepositoryEvent>::from_json_str(body).unwrap();
+        assert_eq!(event.0.previous_name(), Some("octoapp-old"));
+        assert_eq!(event.0.previous_owner(), None);
+        let event = WebHook::<serde_json::Value>::from_json_str(body).unwrap();
+        assert_eq!(event.0.previous_name(), Some("octoapp-old"));
+
+        let event: RepositoryEvent = serde_json::from_str(
+            r#"{"action": "edited", "changes": {"default_branch": {"from": "master"}}}"#,
+        )
+        .unwrap();
+        assert_eq!(event.previous_default_branch(), Some("master"));
+
+        let event: RepositoryEvent = serde_json::from_str(r#"{"action": "privatized"}"#).unwrap();
+        assert!(event.is_visibility_change());
+    }
+
+    #[test]
+    fn test_organization_events() {
+        let event: OrganizationEvent = serde_json::from_str(
+            r#"{"action": "renamed", "changes": {"login": {"from": "octo-org-old"}}}"#,
+        )
+        .unwrap();
+        assert_eq!(event.action(), OrganizationAction::Renamed);
+        assert_eq!(event.previous_login(), Some("octo-org-old"));
+
+        let event: OrganizationEvent = serde_json::from_str(
+            r#"{"action": "member_added", "membership": {"user": {"login": "octocat"}}}"#,
+        )
+        .unwrap();
+        assert_eq!(event.action(), OrganizationAction::MemberAdded);
+        assert_eq!(event.member_login(), Some("octocat"));
+
+        let action: OrganizationAction = serde_json::from_str(r#""archived""#).unwrap();
+        assert_eq!(action, OrganizationAction::Other("archived".to_string()));
+    }
+}
@@ -0,0 +1,88 @@
+//! # Star / Watch Event Helpers
+//!
+//! The user who starred (or watched) the repository is the sender of the
+//! event, see `WebHook::sender()`.
+//!
+//! ```rust
+//! # use octoapp::prelude::*;
+//! # fn handle(webhook: WebHook<StarEvent>) {
+//! if webhook.0.is_starred() {
+//!     let login = webhook.sender().map(|sender| sender.login.as_str());
+//!     println!("Starred by {:?} at {:?}", login, webhook.0.starred_at());
+//! }
+//! # }
+//! ```
+
+pub use octocrab::models::webhook_events::payload::StarWebhookEventAction as StarAction;
+pub use octocrab::models::webhook_events::payload::WatchWebhookEventAction as WatchAction;
+
+use super::payloads::{StarEvent, WatchEvent};
+
+/// Helpers for the Star Event
+pub trait StarEventExt {
+    /// Get the action of the event (`created` or `deleted`)
+    fn action(&self) -> &StarAction;
+    /// Check if the repository was starred (not unstarred)
+    fn is_starred(&self) -> bool;
+    /// Get the time the repository was starred (`None` when unstarred)
+    fn starred_at(&self) -> Option<chrono::DateTime<chrono::Utc>>;
+}
+
+impl StarEventExt for StarEvent {
+    fn action(&self) -> &StarAction {
+        &self.action
+    }
+
+    fn is_starred(&self) -> bool {
+        matches!(self.action, StarAction::Created)
+    }
+
+    fn starred_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.starred_at
+    }
+}
+
+/// Helpers for the Watch Event
+pub trait WatchEventExt {
+    /// Get the action of the event (`started`)
+    fn action(&self) -> &WatchAction;
+}
+
+impl WatchEventExt for WatchEvent {
+    fn action(&self) -> &WatchAction {
+        &self.action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebHook;
+
+    #[test]
+    fn test_star_event() {
+        let webhook = WebHook::<StarEvent>::from_json_str(
+            r#"{
+                "action": "created",
+                "starred_at": "2024-10-01T12:00:00Z",
+                "sender": {"login": "octocat", "id": 1, "type": "User"}
+            }"#,
+        )
+        .unwrap();
+        assert!(webhook.0.is_starred());
+        assert_eq!(
+            webhook.0.starred_at().map(|at| at.to_rfc3339()).as_deref(),
+            Some("2024-10-01T12:00:00+00:00")
+        );
+        assert_eq!(webhook.sender().map(|s| s.login.as_str()), Some("octocat"));
+
+        let event: StarEvent =
+            serde_json::from_str(r#"{"action": "deleted", "starred_at": null}"#).unwrap();
+        assert_eq!(event.action(), &StarAction::Deleted);
+        assert!(!event.is_starred());
+        assert_eq!(event.starred_at(), None);
+
+        let event: WatchEvent = serde_json::from_str(r#"{"action": "started"}"#).unwrap();
+        assert_eq!(event.action(), &WatchAction::Started);
+    }
+}
@@ -0,0 +1,177 @@
+//! # Team / Member / Membership Event Helpers
+//!
+//! The team slug and member login of team and membership changes, and the
+//! collaborator of member events.
+
+use super::{
+    octocrab_enum,
+    payloads::{MemberEvent, MembershipEvent, TeamAddEvent, TeamEvent},
+};
+
+string_enum! {
+    /// Team Event Action
+    TeamAction {
+        /// Team created
+        Created => "created",
+        /// Team deleted
+        Deleted => "deleted",
+        /// Team edited
+        Edited => "edited",
+        /// Repository added to the team
+        AddedToRepository => "added_to_repository",
+        /// Repository removed from the team
+        RemovedFromRepository => "removed_from_repository",
+    }
+}
+
+string_enum! {
+    /// Member Event Action
+    MemberAction {
+        /// Collaborator added
+        Added => "added",
+        /// Collaborator permissions edited
+        Edited => "edited",
+        /// Collaborator removed
+        Removed => "removed",
+    }
+}
+
+string_enum! {
+    /// Membership Event Action
+    MembershipAction {
+        /// User added to the team
+        Added => "added",
+        /// User removed from the team
+        Removed => "removed",
+    }
+}
+
+string_enum! {
+    /// Membership Scope
+    MembershipScope {
+        /// Team membership
+        Team => "team",
+        /// Organization membership
+        Organization => "organization",
+    }
+}
+
+/// Helpers for the Team Event
+pub trait TeamEventExt {
+    /// Get the action of the event
+    fn action(&self) -> TeamAction;
+    /// Get the slug of the team
+    fn team_slug(&self) -> Option<&str>;
+}
+
+impl TeamEventExt for TeamEvent {
+    fn action(&self) -> TeamAction {
+        octocrab_enum(&self.action)
+    }
+
+    fn team_slug(&self) -> Option<&str> {
+        self.team.get("slug")?.as_str()
+    }
+}
+
+/// Helpers for the Team Add Event
+pub trait TeamAddEventExt {
+    /// Get the slug of the team the repository was added to
+    fn team_slug(&self) -> Option<&str>;
+}
+
+impl TeamAddEventExt for TeamAddEvent {
+    fn team_slug(&self) -> Option<&str> {
+        self.team.get("slug")?.as_str()
+    }
+}
+
+/// Helpers for the Member Event
+pub trait MemberEventExt {
+    /// Get the action of the event
+    fn action(&self) -> MemberAction;
+    /// Get the login of the collaborator
+    fn member_login(&self) -> Option<&str>;
+}
+
+impl MemberEventExt for MemberEvent {
+    fn action(&self) -> MemberAction {
+        octocrab_enum(&self.action)
+    }
+
+    fn member_login(&self) -> Option<&str> {
+        self.member.get("login")?.as_str()
+    }
+}
+
+/// Helpers for the Membership Event
+pub trait MembershipEventExt {
+    /// Get the action of the event
+    fn action(&self) -> MembershipAction;
+    /// Get the slug of the team
+    fn team_slug(&self) -> Option<&str>;
+    /// Get the login of the member
+    fn member_login(&self) -> Option<&str>;
+    /// Get the scope of the membership
+    fn scope(&self) -> MembershipScope;
+}
+
+impl MembershipEventExt for MembershipEvent {
+    fn action(&self) -> MembershipAction {
+        octocrab_enum(&self.action)
+    }
+
+    fn team_slug(&self) -> Option<&str> {
+        self.team.get("slug")?.as_str()
+    }
+
+    fn member_login(&self) -> Option<&str> {
+        self.member.get("login")?.as_str()
+    }
+
+    fn scope(&self) -> MembershipScope {
+        octocrab_enum(&self.scope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_team_events() {
+        let event: TeamEvent = serde_json::from_str(
+            r#"{"action": "added_to_repository", "team": {"slug": "maintainers"}}"#,
+        )
+        .unwrap();
+        assert_eq!(event.action(), TeamAction::AddedToRepository);
+        assert_eq!(event.team_slug(), Some("maintainers"));
+
+        let event: TeamAddEvent =
+            serde_json::from_str(r#"{"team": {"slug": "maintainers"}}"#).unwrap();
+        assert_eq!(event.team_slug(), Some("maintainers"));
+
+        let event: MemberEvent =
+            serde_json::from_str(r#"{"action": "edited", "member": {"login": "octocat"}}"#)
+                .unwrap();
+        assert_eq!(event.action(), MemberAction::Edited);
+        assert_eq!(event.member_login(), Some("octocat"));
+
+        let event: MembershipEvent = serde_json::from_str(
+            r#"{
+                "action": "removed",
+                "scope": "team",
+                "member": {"login": "octocat"},
+                "team": {"slug": "maintainers"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(event.action(), MembershipAction::Removed);
+        assert_eq!(event.scope(), MembershipScope::Team);
+        assert_eq!(event.team_slug(), Some("maintainers"));
+        assert_eq!(event.member_login(), Some("octocat"));
+
+        let action: TeamAction = serde_json::from_str(r#""archived""#).unwrap();
+        assert_eq!(action, TeamAction::Other("archived".to_string()));
+    }
+}
@@ -0,0 +1,130 @@
+//! # Typed Event Accessors
+//!
+//! Accessors to get the concrete payload out of an `Event` (or a
+//! `WebHook<Event>`) without matching on the enum.
+//!
+//! ```rust
+//! # use octoapp::prelude::*;
+//! # fn handle(webhook: WebHook<Event>) {
+//! if let Some(issues) = webhook.0.as_issues() {
+//!     println!("Issue #{}", issues.issue.number);
+//! }
+//!
+//! // Keep the installation and sender with the typed payload
+//! match webhook.try_into_typed::<IssuesEvent>() {
+//!     Ok(issues) => println!("Issue #{}", issues.0.issue.number),
+//!     Err(other) => println!("Not an issues event: {}", other.0.kind()),
+//! }
+//! # }
+//! ```
+
+use super::{payloads, Event, WebHook};
+
+/// A payload type of an `Event` variant
+pub trait EventPayload: Sized {
+    /// Get a reference to the payload if the event is of this type
+    fn from_event_ref(event: &Event) -> Option<&Self>;
+    /// Get the payload if the event is of this type, otherwise the event
+    fn from_event(event: Event) -> Result<Self, Event>;
+}
+
+/// Generate the `as_*` / `into_*` accessors and the `EventPayload` impls
+macro_rules! typed_events {
+    ($($kind:ident($payload:ident) => $as:ident, $into:ident;)*) => {
+        impl Event {
+            $(
+                #[doc = concat!("Get the payload if this is a `", stringify!($kind), "` event")]
+                pub fn $as(&self) -> Option<&payloads::$payload> {
+                    match self {
+                        Event::$kind(payload) => Some(payload),
+                        _ => None,
+                    }
+                }
+
+                #[doc = concat!("Take the payload if this is a `", stringify!($kind), "` event")]
+                pub fn $into(self) -> Option<payloads::$payload> {
+                    match self {
+                        Event::$kind(payload) => Some(payload),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+
+        $(
+            impl EventPayload for payloads::$payload {
+                fn from_event_ref(event: &Event) -> Option<&Self> {
+                    event.$as()
+                }
+
+                fn from_event(event: Event) -> Result<Self, Event> {
+                    match event {
+                        Event::$kind(payload) => Ok(payload),
+                        event => Err(event),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+typed_events! {
+    CheckRun(CheckRunEvent) => as_check_run, into_check_run;
+    CheckSuite(CheckSuiteEvent) => as_check_suite, into_check_suite;
+    Create(CreateEvent) => as_create, into_create;
+    Delete(DeleteEvent) => as_delete, into_delete;
+    DiscussionComment(DiscussionCommentEvent) => as_discussion_comment, into_discussion_comment;
+    Discussion(DiscussionEvent) => as_discussion, into_discussion;
+    InstallationRepositories(InstallationRepositoriesEvent) => as_installation_repositories, into_installation_repositories;
+    Installation(InstallationEvent) => as_installation, into_installation;
+    IssueComment(IssueCommentEvent) => as_issue_comment, into_issue_comment;
+    Issues(IssuesEvent) => as_issues, into_issues;
+    Label(LabelEvent) => as_label, into_label;
+    Ping(PingEvent) => as_ping, into_ping;
+    PullRequestReviewComment(PullRequestReviewCommentEvent) => as_pull_request_review_comment, into_pull_request_review_comment;
+    PullRequestReview(PullRequestReviewEvent) => as_pull_request_review, into_pull_request_review;
+    PullRequest(PullRequestEvent) => as_pull_request, into_pull_request;
+    Push(PushEvent) => as_push, into_push;
+    Release(ReleaseEvent) => as_release, into_release;
+    Repository(RepositoryEvent) => as_repository, into_repository;
+    WorkflowJob(WorkflowJobEvent) => as_workflow_job, into_workflow_job;
+}
+
+impl WebHook<Event> {
+    /// Get a reference to the payload if the event is of the type `P`
+    pub fn try_as<P: EventPayload>(&self) -> Option<&P> {
+        P::from_event_ref(&self.0)
+    }
+
+    /// Convert into a WebHook of the payload type `P`
+    ///
+    /// The installation, sender and raw body are kept. If the event is of a
+    /// different type the WebHook is returned unchanged.
+    pub fn try_into_typed<P: EventPayload>(self) -> Result<WebHook<P>, WebHook<Event>> {
+        let WebHook(event, installation, sender, raw_body) = self;
+        match P::from_event(event) {
+            Ok(payload) => Ok(WebHook(payload, installation, sender, raw_body)),
+            Err(event) => Err(WebHook(event, installation, sender, raw_body)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_event_accessors() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/issues_opened.json");
+        let webhook = WebHook::<Event>::from_json_file(path).unwrap();
+
+        assert_eq!(webhook.0.as_issues().map(|i| i.issue.number), Some(42));
+        assert!(webhook.0.as_push().is_none());
+        assert!(webhook.try_as::<payloads::IssuesEvent>().is_some());
+
+        let webhook = webhook.try_into_typed::<payloads::PushEvent>().unwrap_err();
+        let issues = webhook.try_into_typed::<payloads::IssuesEvent>().unwrap();
+        assert_eq!(issues.installation(), 52345678);
+        assert_eq!(issues.into_inner().issue.number, 42);
+    }
+}
@@ -0,0 +1,133 @@
+//! # Commit Comment / Gollum (Wiki) Event Helpers
+//!
+//! The body of commit comments and the wiki pages updated by a Gollum event.
+
+pub use octocrab::models::webhook_events::payload::CommitCommentWebhookEventAction as CommitCommentAction;
+
+use super::payloads::{CommitCommentEvent, GollumEvent};
+
+string_enum! {
+    /// Wiki Page Action
+    WikiPageAction {
+        /// Page created
+        Created => "created",
+        /// Page edited
+        Edited => "edited",
+    }
+}
+
+/// A wiki page created or updated in a Gollum event
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WikiPage {
+    /// The name of the page
+    pub page_name: String,
+    /// The title of the page
+    pub title: String,
+    /// The action performed on the page
+    pub action: WikiPageAction,
+    /// The latest commit SHA of the page
+    pub sha: String,
+    /// The URL of the page on GitHub
+    pub html_url: String,
+    /// The summary of the change (if any)
+    #[serde(default)]
+    pub summary: Option<String>,
+}
+
+/// Helpers for the Gollum (Wiki) Event
+pub trait GollumEventExt {
+    /// Get the wiki pages which were created or updated
+    ///
+    /// Pages missing any of the fields are skipped.
+    fn pages(&self) -> Vec<WikiPage>;
+}
+
+impl GollumEventExt for GollumEvent {
+    fn pages(&self) -> Vec<WikiPage> {
+        self.pages
+            .iter()
+            .filter_map(|page| serde_json::from_value(page.clone()).ok())
+            .collect()
+    }
+}
+
+/// Helpers for the Commit Comment Event
+pub trait CommitCommentEventExt {
+    /// Get the action of the event (`created`)
+    fn action(&self) -> &CommitCommentAction;
+    /// Get the body of the comment
+    fn body(&self) -> Option<&str>;
+    /// Get the SHA of the commented commit
+    fn commit_sha(&self) -> &str;
+    /// Get the path of the file the comment is on (line comments only)
+    fn path(&self) -> Option<&str>;
+}
+
+impl CommitCommentEventExt for CommitCommentEvent {
+    fn action(&self) -> &CommitCommentAction {
+        &self.action
+    }
+
+    fn body(&self) -> Option<&str> {
+        self.comment.body.as_deref()
+    }
+
+    fn commit_sha(&self) -> &str {
+        &self.comment.commit_id
+    }
+
+    fn path(&self) -> Option<&str> {
+        self.comment.path.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebHook;
+
+    #[test]
+    fn test_commit_comment() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/commit_comment_created.json"
+        );
+        let event = WebHook::<CommitCommentEvent>::from_json_file(path)
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(event.action(), &CommitCommentAction::Created);
+        assert_eq!(event.body(), Some("This commit broke the build 🙈"));
+        assert_eq!(
+            event.commit_sha(),
+            "6dcb09b5b57875f334f61aebed695e2e4193db5e"
+        );
+        assert_eq!(event.path(), None);
+    }
+
+    #[test]
+    fn test_gollum_pages() {
+        let event: GollumEvent = serde_json::from_str(
+            r#"{
+                "pages": [
+                    {
+                        "page_name": "Home",
+                        "title": "Home",
+                        "summary": null,
+                        "action": "edited",
+                        "sha": "91ea1bd42aa2ba166b86e8aefe049e9837214e67",
+                        "html_url": "https://github.com/42ByteLabs/octoapp/wiki/Home"
+                    },
+                    {"page_name": "Broken"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let pages = event.pages();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].page_name, "Home");
+        assert_eq!(pages[0].action, WikiPageAction::Edited);
+        assert_eq!(pages[0].sha, "91ea1bd42aa2ba166b86e8aefe049e9837214e67");
+    }
+}
@@ -0,0 +1,182 @@
+//! # AWS Lambda Module
+//!
+//! Handle webhooks in an AWS Lambda function behind API Gateway, an
+//! Application Load Balancer or a Function URL using `lambda_http`.
+//!
+//! ```rust,no_run
+//! use lambda_http::{run, service_fn, Request};
+//! use octoapp::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), lambda_http::Error> {
+//!     let config = OctoAppConfig::init().build()?;
+//!
+//!     run(service_fn(|request: Request| {
+//!         let config = config.clone();
+//!         async move {
+//!             Ok::<_, lambda_http::Error>(
+//!                 octoapp::ghlambda::handle_lambda_event(
+//!                     &config,
+//!                     request,
+//!                     |event: WebHook<Event>| async move {
+//!                         tracing::info!("Received event: {:?}", event.into_inner().kind());
+//!                         Ok(())
+//!                     },
+//!                 )
+//!                 .await,
+//!             )
+//!         }
+//!     }))
+//!     .await
+//! }
+//! ```
+
+use std::future::Future;
+
+use lambda_http::{
+    http::{header::CONTENT_TYPE, HeaderValue, StatusCode},
+    Body, Request, Response,
+};
+
+use crate::{OctoAppConfig, OctoAppError, WebHook};
+
+/// The header GitHub sends the webhook signature in
+const SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+
+/// Handle a webhook delivered to a Lambda function
+///
+/// Verifies the signature, parses the payload into a `WebHook<T>` and calls
+/// the handler. Responds with `200` if the handler succeeded, otherwise with
+/// the status of the error (see `OctoAppError::http_status`), for example
+/// `401` for an invalid signature or `400` for an invalid payload. A `ping`
+/// GitHub sends when creating the webhook is acknowledged with `200` even if
+/// it isn't a `T` payload.
+pub async fn handle_lambda_event<T, F, Fut>(
+    config: &OctoAppConfig,
+    event: Request,
+    handler: F,
+) -> Response<Body>
+where
+    T: serde::de::DeserializeOwned,
+    F: FnOnce(WebHook<T>) -> Fut,
+    Fut: Future<Output = Result<(), OctoAppError>>,
+{
+    let result = match parse::<T>(config, &event) {
+        Ok(webhook) => handler(webhook).await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => response(StatusCode::OK, "ok", None),
+        Err(e) => {
+            let status =
+                StatusCode::from_u16(e.http_status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            if status.is_server_error() {
+                tracing::error!("Failed to handle webhook: {}", e);
+            } else {
+                tracing::debug!("Rejected webhook: {}", e);
+            }
+            response(status, "error", Some(e.to_string()))
+        }
+    }
+}
+
+/// Verify and parse the webhook from the Lambda request
+fn parse<T: serde::de::DeserializeOwned>(
+    config: &OctoAppConfig,
+    event: &Request,
+) -> Result<WebHook<T>, OctoAppError> {
+    let body: &[u8] = event.body().as_ref();
+
+    let verified = match event.headers().get(SIGNATURE_HEADER) {
+        Some(signature) => {
+            let signature = signature.to_str().unwrap_or_default().to_string();
+            config.webhook_signature_verification(body, signature)
+        }
+        None => config.insecure_skip_signature_verification(),
+    };
+    if !verified {
+        return Err(OctoAppError::SignatureError(format!(
+            "Missing or invalid {} header",
+            SIGNATURE_HEADER
+        )));
+    }
+
+    let body = std::str::from_utf8(body)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    if body.trim().is_empty() {
+        return Err(OctoAppError::EmptyBody);
+    }
+    config.check_installation_allowed(crate::events::extract_installation_id(body))?;
+
+    let ping = event
+        .headers()
+        .get("X-GitHub-Event")
+        .is_some_and(|name| name == "ping");
+    if ping {
+        crate::events::log_ping(body);
+    }
+    match WebHook::from_json_str(body) {
+        // Acknowledge the ping even if the handler expects another payload
+        Err(_) if ping => Err(OctoAppError::IgnoredEvent("ping".to_string())),
+        result => result,
+    }
+}
+
+/// Build a JSON response
+fn response(status: StatusCode, state: &str, message: Option<String>) -> Response<Body> {
+    let body = serde_json::json!({ "status": state, "message": message });
+
+    let mut response = Response::new(Body::from(body.to_string()));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::payloads::PingEvent;
+
+    fn request(body: &str, signature: Option<String>) -> Request {
+        let mut request = Request::new(Body::from(body.to_string()));
+        if let Some(signature) = signature {
+            request
+                .headers_mut()
+                .insert(SIGNATURE_HEADER, HeaderValue::from_str(&signature).unwrap());
+        }
+        request
+    }
+
+    #[tokio::test]
+    async fn test_handle_lambda_event() {
+        let config = OctoAppConfig::init()
+            .app_id(12345)
+            .webhook_secret("ThisIsASecret")
+            .build()
+            .unwrap();
+        let body = r#"{"zen": "Design for failure.", "hook_id": 1}"#;
+        let signature = config.sign_payload(body.as_bytes()).unwrap();
+
+        let handler = |event: WebHook<PingEvent>| async move {
+            assert_eq!(event.0.zen.as_deref(), Some("Design for failure."));
+            Ok(())
+        };
+        let response =
+            handle_lambda_event(&config, request(body, Some(signature.clone())), handler).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = handle_lambda_event(&config, request(body, None), handler).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = handle_lambda_event(
+            &config,
+            request(body, Some(signature)),
+            |_: WebHook<PingEvent>| async { Err(OctoAppError::UnknownError) },
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
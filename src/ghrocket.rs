@@ -16,7 +16,13 @@
 //! ```
 //!
 
-use crate::{events::WebHook, OctoAppError};
+use std::sync::Arc;
+
+use crate::{
+    events::{Event, EventKind, WebHook},
+    metrics::{Metrics, WebhookOutcome},
+    OctoAppError,
+};
 use rocket::{
     data::{Data, FromData, Outcome},
     http::Status,
@@ -26,8 +32,15 @@ use rocket::{
 };
 
 pub mod errors;
+pub mod fairing;
+pub mod response;
+
+pub use errors::{catchers, OctoAppResult};
+pub use fairing::OctoAppFairing;
+pub use response::OctoAppResponse;
 
-pub use errors::OctoAppResult;
+/// The default header GitHub sends the webhook signature in
+const SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
 
 /// The application state for the OctoApp
 ///
@@ -35,12 +48,191 @@ pub use errors::OctoAppResult;
 pub struct OctoAppState {
     /// The configuration for the OctoApp
     pub config: crate::OctoAppConfig,
+    /// The header to read the webhook signature from
+    signature_header: String,
+    /// Optional deduplication of webhook deliveries
+    dedup: Option<crate::dedup::DeliveryDedup>,
+    /// Retain the raw body on the WebHook
+    raw_body: bool,
+    /// Accept the legacy form encoded (`payload=`) webhooks
+    form_payload: bool,
+    /// Include the payload parser errors in the responses
+    verbose_errors: bool,
+    /// Reject payloads without an installation
+    require_installation: bool,
+    /// Acknowledge draft pull request events without calling the route
+    skip_draft_prs: bool,
+    /// Record the high signal payload fields on a `webhook` span
+    trace_payloads: bool,
+    /// Optional webhook metrics sink
+    metrics: Option<Arc<dyn Metrics>>,
+    /// Optional filter of the event kinds to process
+    only: Option<Vec<EventKind>>,
+    /// Optional sink to forward verified events to
+    #[cfg(feature = "publish")]
+    sink: Option<Arc<dyn crate::publish::EventSink>>,
 }
 
 impl OctoAppState {
     /// Create a new OctoAppState instance
     pub fn new(config: crate::OctoAppConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            signature_header: SIGNATURE_HEADER.to_string(),
+            dedup: None,
+            raw_body: false,
+            form_payload: false,
+            verbose_errors: false,
+            require_installation: false,
+            skip_draft_prs: false,
+            trace_payloads: false,
+            metrics: None,
+            only: None,
+            #[cfg(feature = "publish")]
+            sink: None,
+        }
+    }
+
+    /// Set the header to read the webhook signature from
+    ///
+    /// Defaults to `X-Hub-Signature-256`. This is useful when a proxy or API
+    /// gateway renames the header, or for testing setups.
+    pub fn signature_header(mut self, header: impl Into<String>) -> Self {
+        self.signature_header = header.into();
+        self
+    }
+
+    /// Enable deduplication of webhook deliveries (`X-GitHub-Delivery` header)
+    ///
+    /// Duplicate deliveries are answered with `200 OK` without calling the route.
+    pub fn dedup(mut self, dedup: crate::dedup::DeliveryDedup) -> Self {
+        self.dedup = Some(dedup);
+        self
+    }
+
+    /// Retain the raw body on the WebHook (`WebHook::raw_body()`)
+    ///
+    /// Disabled by default to avoid keeping a second copy of each payload.
+    pub fn raw_body(mut self, raw_body: bool) -> Self {
+        self.raw_body = raw_body;
+        self
+    }
+
+    /// Accept webhooks sent as `application/x-www-form-urlencoded` (default: false)
+    ///
+    /// GitHub sends the JSON payload in the `payload` form field when the
+    /// webhook is configured with the legacy form content type. Otherwise only
+    /// `application/json` bodies are accepted.
+    pub fn form_payload(mut self, form_payload: bool) -> Self {
+        self.form_payload = form_payload;
+        self
+    }
+
+    /// Include the payload parser errors in `400` responses (default: false)
+    ///
+    /// The error includes the path of the field and the line / column which
+    /// failed to parse. Disabled by default to avoid leaking internals, the
+    /// error is always logged.
+    pub fn verbose_errors(mut self, verbose_errors: bool) -> Self {
+        self.verbose_errors = verbose_errors;
+        self
+    }
+
+    /// Reject payloads without an installation with `400` (default: false)
+    ///
+    /// For apps which only process installation scoped events. App level
+    /// events (like `github_app_authorization`) have no installation so they
+    /// are rejected too.
+    pub fn require_installation(mut self, require_installation: bool) -> Self {
+        self.require_installation = require_installation;
+        self
+    }
+
+    /// Skip `pull_request` events of draft pull requests (default: false)
+    ///
+    /// The events are answered with `200 OK` without calling the route, like
+    /// the events filtered out with `only`.
+    pub fn skip_draft_prs(mut self, skip_draft_prs: bool) -> Self {
+        self.skip_draft_prs = skip_draft_prs;
+        self
+    }
+
+    /// Record the high signal payload fields as tracing fields (default: false)
+    ///
+    /// After parsing, a `Received webhook` event is logged in a `webhook` span
+    /// with the event, delivery, repository, action, sender and issue / pull
+    /// request number. The body and secrets are never recorded.
+    pub fn trace_payloads(mut self, trace_payloads: bool) -> Self {
+        self.trace_payloads = trace_payloads;
+        self
+    }
+
+    /// Set the sink for webhook metrics
+    ///
+    /// The signature and parse outcomes are recorded, the handler outcome is
+    /// up to the route as Rocket calls it after the data guard.
+    pub fn metrics(mut self, metrics: impl Metrics + 'static) -> Self {
+        self.metrics = Some(Arc::new(metrics));
+        self
+    }
+
+    /// Get an Octocrab instance for the installation
+    ///
+    /// See `OctoAppConfig::octocrab_by_installation`.
+    pub async fn octocrab_by_installation(
+        &self,
+        installation_id: u64,
+    ) -> Result<octocrab::Octocrab, OctoAppError> {
+        self.config.octocrab_by_installation(installation_id).await
+    }
+
+    /// Get an Octocrab instance authenticated as the app (JWT)
+    ///
+    /// See `OctoAppConfig::octocrab_app`.
+    pub fn octocrab_app(&self) -> Result<octocrab::Octocrab, OctoAppError> {
+        self.config.octocrab_app()
+    }
+
+    /// Only process the given event kinds
+    ///
+    /// Other events (by the `X-GitHub-Event` header) are answered with
+    /// `200 OK` before the payload is read, without calling the route.
+    pub fn only(mut self, kinds: &[EventKind]) -> Self {
+        self.only = Some(kinds.to_vec());
+        self
+    }
+
+    /// Set the sink to forward every verified event to
+    ///
+    /// Events are published after the signature is verified (and known
+    /// duplicates dropped) and before the payload is parsed. If publishing
+    /// fails the request fails, and as the delivery is only recorded once the
+    /// request is accepted, GitHub's redelivery is published again.
+    #[cfg(feature = "publish")]
+    pub fn sink(mut self, sink: impl crate::publish::EventSink + 'static) -> Self {
+        self.sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Check if the event (`X-GitHub-Event` header) is filtered out
+    fn is_ignored(&self, event: &str) -> bool {
+        match &self.only {
+            Some(kinds) => !event
+                .parse::<EventKind>()
+                .is_ok_and(|kind| kinds.contains(&kind)),
+            None => false,
+        }
+    }
+
+    /// Record a webhook outcome (if a metrics sink is set)
+    fn record(&self, req: &Request<'_>, outcome: WebhookOutcome) {
+        if let Some(metrics) = &self.metrics {
+            let kind = req
+                .headers()
+                .get_one("X-GitHub-Event")
+                .and_then(|event| event.parse::<EventKind>().ok());
+            metrics.record(kind, outcome);
+        }
     }
 }
 
@@ -50,43 +242,24 @@ impl From<crate::OctoAppConfig> for OctoAppState {
     }
 }
 
-/// Deserialize a WebHook from a string to extract the installation ID
-#[derive(serde::Deserialize)]
-#[non_exhaustive]
-struct ReqBlob {
-    installation: InsBlob,
-}
-
-/// Deserialize a WebHook from a string to extract the installation ID
-#[derive(serde::Deserialize)]
-#[non_exhaustive]
-struct InsBlob {
-    id: u64,
-}
-
 /// Deserialize a WebHook from a string for Rocket
-impl<'r, T: serde::Deserialize<'r>> WebHook<T> {
-    fn from_str(s: &'r str) -> Result<Self, crate::OctoAppError> {
-        // TODO: This is a little hacky, but it works 🤷
-        // We do deserialization in two steps here to extract the
-        // installation ID and the payload.
-
-        let id: u64 = match serde_json::from_str::<ReqBlob>(s) {
-            Ok(installation) => installation.installation.id,
-            Err(_) => 0,
-        };
-
-        serde_json::from_str(s)
-            .map(|value| WebHook(value, id))
-            .map_err(|e| crate::OctoAppError::from(e))
-    }
-
+impl<'r, T: serde::Deserialize<'r> + Send> WebHook<T> {
     async fn from_data(
         req: &'r ::rocket::request::Request<'_>,
         data: ::rocket::data::Data<'r>,
         appstate: &State<OctoAppState>,
         signature: String,
     ) -> Result<Self, crate::OctoAppError> {
+        // GitHub sends JSON, or the JSON in a `payload` form field (opt-in)
+        let form = match req.content_type() {
+            Some(content_type) if content_type.is_json() => false,
+            Some(content_type) if content_type.is_form() && appstate.form_payload => true,
+            Some(content_type) => {
+                return Err(OctoAppError::UnsupportedMediaType(content_type.to_string()));
+            }
+            None => false,
+        };
+
         let limit = req
             .limits()
             .get("json")
@@ -99,24 +272,129 @@ impl<'r, T: serde::Deserialize<'r>> WebHook<T> {
             }
             Err(e) => return Err(crate::OctoAppError::from(e)),
         };
+        // The body is cached for the lifetime of the request (the payload can
+        // borrow from it), the same bytes are verified and parsed.
+        let body: &'r str = ::rocket::request::local_cache!(req, string);
 
         // Validate the request signature
         if !appstate
             .config
-            .webhook_signature_verification(string.as_bytes(), signature)
+            .webhook_signature_verification(body.as_bytes(), signature)
         {
             // Failed to validate the request signature
+            appstate.record(req, WebhookOutcome::SignatureFailed);
             return Err(OctoAppError::SignatureError(
                 "Failed to validate the request signature".to_string(),
             ));
         }
+        appstate.record(req, WebhookOutcome::SignatureOk);
 
-        Self::from_str(::rocket::request::local_cache!(req, string))
+        // Drop known duplicates before publishing, they are recorded below
+        if let (Some(dedup), Some(delivery)) =
+            (&appstate.dedup, req.headers().get_one("X-GitHub-Delivery"))
+        {
+            if dedup.is_seen(delivery).await {
+                return Err(OctoAppError::DuplicateDelivery(delivery.to_string()));
+            }
+        }
+
+        let raw_body: Option<std::sync::Arc<str>> = appstate.raw_body.then(|| body.into());
+        // The signature covers the whole form body, the payload is decoded after
+        let body: &'r str = if form {
+            let payload = crate::events::form_payload(body)?;
+            ::rocket::request::local_cache!(req, payload)
+        } else {
+            body
+        };
+        if body.trim().is_empty() {
+            return Err(OctoAppError::EmptyBody);
+        }
+        appstate
+            .config
+            .check_installation_allowed(crate::events::extract_installation_id(body))?;
+
+        #[cfg(feature = "publish")]
+        if let Some(sink) = &appstate.sink {
+            match req
+                .headers()
+                .get_one("X-GitHub-Event")
+                .and_then(|event| event.parse::<EventKind>().ok())
+            {
+                Some(kind) => {
+                    let delivery = req.headers().get_one("X-GitHub-Delivery");
+                    sink.publish(kind, body.as_bytes(), delivery).await?;
+                }
+                None => tracing::debug!("Not publishing event without a known kind"),
+            }
+        }
+
+        if appstate.skip_draft_prs
+            && req.headers().get_one("X-GitHub-Event") == Some("pull_request")
+            && crate::events::is_draft_pull_request(body)
+        {
+            return Err(OctoAppError::IgnoredEvent("draft pull_request".to_string()));
+        }
+
+        let ping = req.headers().get_one("X-GitHub-Event") == Some("ping");
+        if ping {
+            crate::events::log_ping(body);
+        }
+
+        let webhook = match Self::from_json_str(body) {
+            Ok(webhook) => {
+                appstate.record(req, WebhookOutcome::ParseOk);
+                if appstate.trace_payloads {
+                    // Rocket calls the route after the data guard, so the
+                    // span can't wrap it
+                    crate::events::webhook_span(
+                        req.headers().get_one("X-GitHub-Event"),
+                        req.headers().get_one("X-GitHub-Delivery"),
+                        body,
+                    )
+                    .in_scope(|| tracing::info!("Received webhook"));
+                }
+                webhook
+            }
+            // Acknowledge the ping even if the route expects another payload
+            Err(_) if ping => {
+                return Err(OctoAppError::IgnoredEvent("ping".to_string()));
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to parse {} webhook (delivery {}): {}",
+                    req.headers().get_one("X-GitHub-Event").unwrap_or("unknown"),
+                    req.headers()
+                        .get_one("X-GitHub-Delivery")
+                        .unwrap_or("unknown"),
+                    e
+                );
+                appstate.record(req, WebhookOutcome::ParseFailed);
+                return Err(e);
+            }
+        };
+        if appstate.require_installation && webhook.installation() == 0 {
+            return Err(OctoAppError::MissingInstallation);
+        }
+
+        // Only record deliveries that were accepted, so a redelivery of a
+        // rejected one is processed again
+        if let (Some(dedup), Some(delivery)) =
+            (&appstate.dedup, req.headers().get_one("X-GitHub-Delivery"))
+        {
+            if dedup.is_duplicate(delivery).await {
+                return Err(OctoAppError::DuplicateDelivery(delivery.to_string()));
+            }
+        }
+
+        Ok(match raw_body {
+            Some(raw_body) => webhook.with_raw_body(raw_body),
+            None => webhook,
+        })
     }
 }
 
 #[rocket::async_trait]
-impl<'r, T: serde::Deserialize<'r>> FromData<'r> for WebHook<T> {
+impl<'r, T: serde::Deserialize<'r> + Send> FromData<'r> for WebHook<T> {
     type Error = crate::OctoAppError;
 
     async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
@@ -124,24 +402,61 @@ impl<'r, T: serde::Deserialize<'r>> FromData<'r> for WebHook<T> {
         let appstate: &State<super::OctoAppState> =
             req.guard::<&State<OctoAppState>>().await.unwrap();
 
+        // Acknowledge filtered out events without reading the payload
+        if let Some(event) = req.headers().get_one("X-GitHub-Event") {
+            if appstate.is_ignored(event) {
+                return Outcome::Error((Status::Ok, OctoAppError::IgnoredEvent(event.to_string())));
+            }
+        }
+
         // Validate the request signature
-        let signature: String = match req.headers().get_one("X-Hub-Signature-256") {
+        let signature: String = match req.headers().get_one(&appstate.signature_header) {
             Some(signature) => signature.to_string(),
+            None if appstate.config.insecure_skip_signature_verification() => String::new(),
             None => {
+                appstate.record(req, WebhookOutcome::SignatureFailed);
                 return Outcome::Error((
                     rocket::http::Status::Unauthorized,
-                    OctoAppError::SignatureError("Missing X-Hub-Signature-256 header".to_string()),
-                ))
+                    OctoAppError::SignatureError(format!(
+                        "Missing {} header",
+                        appstate.signature_header
+                    )),
+                ));
             }
         };
 
+        // Duplicate and ignored deliveries are acknowledged without calling the route
         match Self::from_data(req, data, appstate, signature).await {
             Ok(value) => Outcome::Success(value),
-            Err(e) => Outcome::Error((Status::BadRequest, e)),
+            Err(e) => Outcome::Error((errors::status(&e), e)),
         }
     }
 }
 
+/// Verification only webhook route
+///
+/// Verifies the signature and parses the payload without calling any handler,
+/// responding with `200`, `401` (invalid signature) or `400` (invalid payload).
+/// This is useful for checking the webhook secret and canary deployments.
+///
+/// ```rust,no_run
+/// # use octoapp::prelude::*;
+/// # fn build(state: OctoAppState) -> rocket::Rocket<rocket::Build> {
+/// rocket::build()
+///     .manage(state)
+///     .mount("/github/verify", rocket::routes![octoapp::ghrocket::verify_only])
+/// # }
+/// ```
+#[rocket::post("/", data = "<event>")]
+pub async fn verify_only(event: OctoAppResult<WebHook<Event>>) -> OctoAppResult<()> {
+    let event = event?;
+    tracing::debug!(
+        "Verified webhook for installation: {}",
+        event.installation()
+    );
+    Ok(())
+}
+
 impl<'r, T: serde::Serialize> rocket::response::Responder<'r, 'r> for WebHook<T> {
     fn respond_to(self, req: &'r Request<'_>) -> rocket::response::Result<'r> {
         content::RawJson(serde_json::to_string(&self.0).map_err(|_| Status::InternalServerError)?)
@@ -154,3 +469,398 @@ impl<'r, T: serde::Serialize> rocket::response::Responder<'r, 'r> for WebHook<T>
 //         Json(json!({ "status": "success"})).respond_to(request)
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::payloads::{IssuesEvent, PingEvent};
+    use rocket::{
+        http::{ContentType, Header},
+        local::asynchronous::Client,
+    };
+
+    const SECRET: &str = "ThisIsASecret";
+
+    async fn client(state: OctoAppState) -> Client {
+        let rocket = rocket::build()
+            .manage(state)
+            .mount("/", rocket::routes![ping]);
+        Client::tracked(rocket).await.unwrap()
+    }
+
+    fn state() -> OctoAppState {
+        let config = crate::OctoAppConfig::init()
+            .app_id(1)
+            .webhook_secret(SECRET)
+            .build()
+            .unwrap();
+        OctoAppState::new(config)
+    }
+
+    #[rocket::post("/", data = "<event>")]
+    async fn ping(event: OctoAppResult<WebHook<PingEvent>>) -> OctoAppResult<String> {
+        let event = event?;
+        let raw_body = String::from_utf8_lossy(event.raw_body().unwrap_or_default()).to_string();
+        Ok(format!(
+            "{}\n{}",
+            event.into_inner().zen.unwrap_or_default(),
+            raw_body
+        ))
+    }
+
+    #[rocket::async_test]
+    async fn test_multibyte_payload() {
+        let body = r#"{"zen": "Ünïcödé is 🦀 ✓", "hook_id": 1}"#;
+        let client = client(state().raw_body(true)).await;
+
+        let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+        let response = client
+            .post("/")
+            .header(ContentType::JSON)
+            .header(Header::new(SIGNATURE_HEADER, signature))
+            .body(body)
+            .dispatch()
+            .await;
+
+        // The verified bytes are the parsed (and retained) bytes
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.into_string().await.unwrap(),
+            format!("Ünïcödé is 🦀 ✓\n{}", body)
+        );
+    }
+
+    #[rocket::async_test]
+    async fn test_content_type() {
+        let body = r#"{"zen": "Design for failure.", "hook_id": 1}"#;
+        let form = format!("payload={}", body.replace(' ', "+"));
+
+        for (form_payload, content_type, data, status) in [
+            (
+                false,
+                ContentType::Plain,
+                body.to_string(),
+                Status::UnsupportedMediaType,
+            ),
+            (
+                false,
+                ContentType::Form,
+                form.clone(),
+                Status::UnsupportedMediaType,
+            ),
+            (true, ContentType::Form, form.clone(), Status::Ok),
+        ] {
+            let client = client(state().form_payload(form_payload)).await;
+            let signature = crate::config::webhook_signature(SECRET, data.as_bytes());
+            let response = client
+                .post("/")
+                .header(content_type)
+                .header(Header::new(SIGNATURE_HEADER, signature))
+                .body(data)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), status);
+        }
+    }
+
+    #[rocket::async_test]
+    async fn test_invalid_form_payload() {
+        let client = client(state().form_payload(true)).await;
+
+        for body in ["zen=Design+for+failure.", "payload=%ZZ"] {
+            let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+            let response = client
+                .post("/")
+                .header(ContentType::Form)
+                .header(Header::new(SIGNATURE_HEADER, signature))
+                .body(body)
+                .dispatch()
+                .await;
+            // A client error, GitHub shouldn't redeliver it
+            assert_eq!(response.status(), Status::BadRequest, "{}", body);
+        }
+    }
+
+    #[rocket::async_test]
+    async fn test_verbose_errors() {
+        let body = r#"{"zen": 42, "hook_id": 1}"#;
+        let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+
+        for (verbose, message) in [
+            (false, "Invalid webhook payload"),
+            (true, "Invalid webhook payload at `zen`"),
+        ] {
+            let client = client(state().verbose_errors(verbose)).await;
+            let response = client
+                .post("/")
+                .header(ContentType::JSON)
+                .header(Header::new(SIGNATURE_HEADER, signature.clone()))
+                .body(body)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), Status::BadRequest);
+
+            let error: errors::OctoAppApiError = response.into_json().await.unwrap();
+            let error = error.message.unwrap();
+            assert!(error.starts_with(message), "{}", error);
+            assert_eq!(error.contains("line 1"), verbose);
+        }
+    }
+
+    #[rocket::post("/", data = "<event>")]
+    async fn guarded(event: WebHook<PingEvent>) -> String {
+        event.into_inner().zen.unwrap_or_default()
+    }
+
+    #[rocket::async_test]
+    async fn test_catchers() {
+        let figment = rocket::Config::figment().merge((
+            "limits",
+            rocket::data::Limits::new().limit("json", 16.into()),
+        ));
+        let rocket = rocket::custom(figment)
+            .manage(state())
+            .mount("/", rocket::routes![guarded])
+            .register("/", catchers());
+        let client = Client::tracked(rocket).await.unwrap();
+
+        let large = r#"{"zen": "Design for failure.", "hook_id": 1}"#;
+        for (body, signature, status) in [
+            ("{}", "sha256=00".to_string(), Status::Unauthorized),
+            (
+                "{\"zen\": 42}",
+                crate::config::webhook_signature(SECRET, b"{\"zen\": 42}"),
+                Status::BadRequest,
+            ),
+            (
+                large,
+                crate::config::webhook_signature(SECRET, large.as_bytes()),
+                Status::PayloadTooLarge,
+            ),
+        ] {
+            let response = client
+                .post("/")
+                .header(ContentType::JSON)
+                .header(Header::new(SIGNATURE_HEADER, signature))
+                .body(body)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), status);
+            assert_eq!(response.content_type(), Some(ContentType::JSON));
+        }
+    }
+
+    #[rocket::post("/issues", data = "<event>")]
+    async fn issues(event: OctoAppResult<WebHook<IssuesEvent>>) -> OctoAppResult<String> {
+        Ok(event?.into_inner().issue.title)
+    }
+
+    #[rocket::async_test]
+    async fn test_ping_without_route() {
+        let rocket = rocket::build()
+            .manage(state())
+            .mount("/", rocket::routes![issues]);
+        let client = Client::tracked(rocket).await.unwrap();
+
+        let body = r#"{"zen": "Design for failure.", "hook_id": 1}"#;
+        let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+        for (event, status) in [("ping", Status::Ok), ("issues", Status::BadRequest)] {
+            let response = client
+                .post("/issues")
+                .header(ContentType::JSON)
+                .header(Header::new(SIGNATURE_HEADER, signature.clone()))
+                .header(Header::new("X-GitHub-Event", event))
+                .body(body)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), status);
+        }
+    }
+
+    #[rocket::async_test]
+    async fn test_empty_body() {
+        let client = client(state()).await;
+
+        for body in ["", " \n"] {
+            let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+            let response = client
+                .post("/")
+                .header(ContentType::JSON)
+                .header(Header::new(SIGNATURE_HEADER, signature))
+                .body(body)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), Status::BadRequest);
+
+            let error: errors::OctoAppApiError = response.into_json().await.unwrap();
+            assert_eq!(
+                error.message.as_deref(),
+                Some("Empty webhook body, expected a JSON payload")
+            );
+        }
+    }
+
+    #[rocket::async_test]
+    async fn test_require_installation() {
+        let client = client(state().require_installation(true)).await;
+
+        for (body, status) in [
+            (
+                r#"{"zen": "Design for failure.", "hook_id": 1}"#,
+                Status::BadRequest,
+            ),
+            (
+                r#"{"zen": "Design for failure.", "hook_id": 1, "installation": {"id": 42}}"#,
+                Status::Ok,
+            ),
+        ] {
+            let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+            let response = client
+                .post("/")
+                .header(ContentType::JSON)
+                .header(Header::new(SIGNATURE_HEADER, signature))
+                .body(body)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), status);
+        }
+    }
+
+    #[rocket::async_test]
+    async fn test_dedup() {
+        let client = client(state().dedup(crate::dedup::DeliveryDedup::default())).await;
+
+        for (delivery, body, status, handled) in [
+            // Rejected deliveries are not recorded
+            ("1", r#"{"zen": 42}"#, Status::BadRequest, false),
+            ("1", r#"{"zen": 42}"#, Status::BadRequest, false),
+            ("2", r#"{"zen": "Design for failure."}"#, Status::Ok, true),
+            ("2", r#"{"zen": "Design for failure."}"#, Status::Ok, false),
+        ] {
+            let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+            let response = client
+                .post("/")
+                .header(ContentType::JSON)
+                .header(Header::new(SIGNATURE_HEADER, signature))
+                .header(Header::new("X-GitHub-Delivery", delivery))
+                .body(body)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), status);
+            let text = response.into_string().await.unwrap();
+            assert_eq!(text.starts_with("Design for failure."), handled, "{}", text);
+        }
+    }
+
+    #[cfg(feature = "publish")]
+    #[rocket::async_test]
+    async fn test_dedup_publish_failure() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Fails the first publish
+        struct FlakySink(Arc<AtomicUsize>);
+
+        #[async_trait::async_trait]
+        impl crate::publish::EventSink for FlakySink {
+            async fn publish(
+                &self,
+                _kind: EventKind,
+                _body: &[u8],
+                _delivery_id: Option<&str>,
+            ) -> Result<(), OctoAppError> {
+                match self.0.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(OctoAppError::PublishError("unavailable".to_string())),
+                    _ => Ok(()),
+                }
+            }
+        }
+
+        let published = Arc::new(AtomicUsize::new(0));
+        let state = state()
+            .dedup(crate::dedup::DeliveryDedup::default())
+            .sink(FlakySink(published.clone()));
+        let client = client(state).await;
+
+        let body = r#"{"zen": "Design for failure."}"#;
+        let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+        // The redelivery of the failed publish is published again, the
+        // duplicate of the accepted delivery isn't
+        for status in [Status::BadGateway, Status::Ok, Status::Ok] {
+            let response = client
+                .post("/")
+                .header(ContentType::JSON)
+                .header(Header::new(SIGNATURE_HEADER, signature.clone()))
+                .header(Header::new("X-GitHub-Event", "ping"))
+                .header(Header::new("X-GitHub-Delivery", "1"))
+                .body(body)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), status);
+        }
+        assert_eq!(published.load(Ordering::SeqCst), 2);
+    }
+
+    #[rocket::async_test]
+    async fn test_allowed_installations() {
+        let config = state()
+            .config
+            .to_builder()
+            .allowed_installations([42])
+            .build()
+            .unwrap();
+        let client = client(OctoAppState::new(config)).await;
+
+        for (body, status) in [
+            (
+                r#"{"zen": "Design for failure.", "hook_id": 1, "installation": {"id": 42}}"#,
+                Status::Ok,
+            ),
+            (
+                r#"{"zen": "Design for failure.", "hook_id": 1, "installation": {"id": 7}}"#,
+                Status::Forbidden,
+            ),
+            (
+                r#"{"zen": "Design for failure.", "hook_id": 1}"#,
+                Status::Ok,
+            ),
+        ] {
+            let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+            let response = client
+                .post("/")
+                .header(ContentType::JSON)
+                .header(Header::new(SIGNATURE_HEADER, signature))
+                .body(body)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), status);
+        }
+    }
+
+    #[rocket::async_test]
+    async fn test_skip_draft_prs() {
+        let draft = r#"{"action": "opened", "number": 1, "pull_request": {"draft": true}}"#;
+        let ready = r#"{"action": "opened", "number": 1, "pull_request": {"draft": false}}"#;
+
+        for (skip, body, status) in [
+            (true, draft, Status::Ok),
+            // Not skipped, the route's (issues) payload fails to parse
+            (true, ready, Status::BadRequest),
+            (false, draft, Status::BadRequest),
+        ] {
+            let rocket = rocket::build()
+                .manage(state().skip_draft_prs(skip))
+                .mount("/", rocket::routes![issues]);
+            let client = Client::tracked(rocket).await.unwrap();
+            let signature = crate::config::webhook_signature(SECRET, body.as_bytes());
+            let response = client
+                .post("/issues")
+                .header(ContentType::JSON)
+                .header(Header::new("X-GitHub-Event", "pull_request"))
+                .header(Header::new(SIGNATURE_HEADER, signature))
+                .body(body)
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), status);
+        }
+    }
+}
@@ -38,27 +38,95 @@ pub struct OctoAppApiError {
     pub message: Option<String>,
 }
 
+/// The HTTP status for an error, shared by the responder and the request guard
+pub(crate) fn status(error: &OctoAppError) -> Status {
+    Status::new(error.http_status())
+}
+
 impl<'r> Responder<'r, 'r> for OctoAppError {
     fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
-        let status = match self {
-            OctoAppError::OctocrabError(_) => Status::InternalServerError,
-            OctoAppError::OctocrabInstallationError(_) => Status::InternalServerError,
-            _ => Status::BadRequest,
+        let status = status(&self);
+
+        // Payload errors include the parser details, only shown if enabled
+        let verbose = request
+            .rocket()
+            .state::<super::OctoAppState>()
+            .is_some_and(|state| state.verbose_errors);
+        let error = match self {
+            OctoAppError::PayloadError { .. } | OctoAppError::JsonSerializationError(_)
+                if !verbose =>
+            {
+                OctoAppApiError {
+                    status: "error".to_string(),
+                    message: Some("Invalid webhook payload".to_string()),
+                }
+            }
+            error => OctoAppApiError::from(error),
         };
 
-        ApiResponse::InternalServerError {
-            inner: (
-                status,
-                Json(OctoAppApiError {
-                    message: Some("Internal Server Error".to_string()),
-                    status: self.to_string(),
-                }),
-            ),
-        }
-        .respond_to(request)
+        (status, Json(error)).respond_to(request)
     }
 }
 
+/// The JSON error response for a status
+fn catch_status(status: Status) -> Json<OctoAppApiError> {
+    Json(OctoAppApiError {
+        status: "error".to_string(),
+        message: Some(status.reason_lossy().to_string()),
+    })
+}
+
+/// Bad Request Catcher
+#[rocket::catch(400)]
+pub fn bad_request() -> Json<OctoAppApiError> {
+    catch_status(Status::BadRequest)
+}
+
+/// Unauthorized Catcher (invalid or missing signature)
+#[rocket::catch(401)]
+pub fn unauthorized() -> Json<OctoAppApiError> {
+    catch_status(Status::Unauthorized)
+}
+
+/// Payload Too Large Catcher
+#[rocket::catch(413)]
+pub fn payload_too_large() -> Json<OctoAppApiError> {
+    catch_status(Status::PayloadTooLarge)
+}
+
+/// Unsupported Media Type Catcher
+#[rocket::catch(415)]
+pub fn unsupported_media_type() -> Json<OctoAppApiError> {
+    catch_status(Status::UnsupportedMediaType)
+}
+
+/// Internal Server Error Catcher
+#[rocket::catch(500)]
+pub fn internal_server_error() -> Json<OctoAppApiError> {
+    catch_status(Status::InternalServerError)
+}
+
+/// JSON error catchers for the webhook routes
+///
+/// Rocket responds with HTML error pages when a data guard fails and the
+/// route doesn't take an `OctoAppResult`, these respond with the same JSON as
+/// the `OctoAppError` responder.
+///
+/// ```rust,no_run
+/// # fn build() -> rocket::Rocket<rocket::Build> {
+/// rocket::build().register("/github", octoapp::ghrocket::catchers())
+/// # }
+/// ```
+pub fn catchers() -> Vec<rocket::Catcher> {
+    rocket::catchers![
+        bad_request,
+        unauthorized,
+        payload_too_large,
+        unsupported_media_type,
+        internal_server_error
+    ]
+}
+
 impl From<OctoAppError> for OctoAppApiError {
     fn from(value: OctoAppError) -> Self {
         OctoAppApiError {
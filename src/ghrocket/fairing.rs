@@ -0,0 +1,180 @@
+//! OctoApp Rocket Fairing
+//!
+//! Builds the `OctoAppConfig` when Rocket ignites, installs the app and
+//! manages the `OctoAppState`. The configuration is read from the `octoapp`
+//! table of Rocket's figment (`Rocket.toml` or `ROCKET_OCTOAPP_*` environment
+//! variables) on top of the usual environment variables.
+//!
+//! ```toml
+//! [default.octoapp]
+//! app_id = 12345
+//! client_key_path = "./private-key.pem"
+//! webhook_secret = "ThisIsASecret"
+//! ```
+//!
+//! ```rust,no_run
+//! # use octoapp::ghrocket::OctoAppFairing;
+//! # fn build() -> rocket::Rocket<rocket::Build> {
+//! rocket::build().attach(OctoAppFairing::default())
+//! # }
+//! ```
+use std::path::PathBuf;
+
+use rocket::{
+    fairing::{self, Fairing, Info, Kind},
+    serde::Deserialize,
+    Build, Rocket,
+};
+
+use super::OctoAppState;
+use crate::OctoAppConfig;
+
+/// The OctoApp settings in Rocket's figment (`octoapp` table)
+#[derive(Debug, Default, Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct OctoAppSettings {
+    app_name: Option<String>,
+    app_id: Option<u64>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    client_key: Option<String>,
+    client_key_path: Option<PathBuf>,
+    webhook_secret: Option<String>,
+}
+
+/// Customize the state before it is managed
+type StateFn = Box<dyn Fn(OctoAppState) -> OctoAppState + Send + Sync>;
+
+/// OctoApp Fairing
+///
+/// Attach to Rocket to build, install and manage the `OctoAppState`.
+pub struct OctoAppFairing {
+    install: bool,
+    state: Option<StateFn>,
+}
+
+impl Default for OctoAppFairing {
+    fn default() -> Self {
+        Self {
+            install: true,
+            state: None,
+        }
+    }
+}
+
+impl OctoAppFairing {
+    /// Fetch the installations of the app at launch (default: true)
+    pub fn install(mut self, install: bool) -> Self {
+        self.install = install;
+        self
+    }
+
+    /// Customize the state (for example `raw_body` or `dedup`) before it is managed
+    pub fn state<F>(mut self, f: F) -> Self
+    where
+        F: Fn(OctoAppState) -> OctoAppState + Send + Sync + 'static,
+    {
+        self.state = Some(Box::new(f));
+        self
+    }
+
+    /// Build (and install) the config using the Rocket settings
+    async fn config(
+        &self,
+        settings: OctoAppSettings,
+    ) -> Result<OctoAppConfig, crate::OctoAppError> {
+        let mut builder = OctoAppConfig::init();
+        if let Some(app_name) = settings.app_name {
+            builder = builder.app_name(app_name);
+        }
+        if let Some(app_id) = settings.app_id {
+            builder = builder.app_id(app_id);
+        }
+        if let Some(client_id) = settings.client_id {
+            builder = builder.client_id(client_id);
+        }
+        if let Some(client_secret) = settings.client_secret {
+            builder = builder.client_secret(client_secret);
+        }
+        if let Some(client_key) = settings.client_key {
+            builder = builder.client_key(client_key);
+        }
+        if let Some(client_key_path) = settings.client_key_path {
+            builder = builder.client_key_path(client_key_path);
+        }
+        if let Some(webhook_secret) = settings.webhook_secret {
+            builder = builder.webhook_secret(webhook_secret);
+        }
+
+        let config = builder.build()?;
+        if self.install {
+            let installations = config.install().await?;
+            tracing::info!("Installed app with {} installations", installations.len());
+        }
+        Ok(config)
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for OctoAppFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "OctoApp",
+            kind: Kind::Ignite,
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        let settings: OctoAppSettings = match rocket.figment().focus("octoapp").extract() {
+            Ok(settings) => settings,
+            Err(e) => {
+                tracing::error!("Invalid OctoApp settings: {}", e);
+                return Err(rocket);
+            }
+        };
+
+        match self.config(settings).await {
+            Ok(config) => {
+                let state = OctoAppState::new(config);
+                let state = match &self.state {
+                    Some(f) => f(state),
+                    None => state,
+                };
+                Ok(rocket.manage(state))
+            }
+            Err(e) => {
+                tracing::error!("Failed to configure OctoApp: {}", e);
+                Err(rocket)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rocket::async_test]
+    async fn test_fairing_manages_state() {
+        let figment = rocket::Config::figment()
+            .merge(("octoapp.app_id", 12345))
+            .merge(("octoapp.webhook_secret", "ThisIsASecret"));
+        let rocket = rocket::custom(figment)
+            .attach(
+                OctoAppFairing::default()
+                    .install(false)
+                    .state(|state| state.raw_body(true)),
+            )
+            .ignite()
+            .await
+            .unwrap();
+
+        let state = rocket.state::<OctoAppState>().unwrap();
+        assert_eq!(state.config.app_id(), 12345);
+        assert_eq!(
+            state.config.webhook_secret().map(String::as_str),
+            Some("ThisIsASecret")
+        );
+        assert!(state.raw_body);
+    }
+}
@@ -0,0 +1,51 @@
+//! OctoApp Rocket Responses
+use rocket::{
+    http::Status,
+    response::{self, Responder},
+    serde::json::Json,
+    Request,
+};
+
+use super::errors::OctoAppApiError;
+
+/// Webhook Response
+///
+/// Lets a route tell GitHub (and anyone reading the access logs) what it did
+/// with the webhook.
+///
+/// ```rust,no_run
+/// # use octoapp::prelude::*;
+/// #[rocket::post("/", data = "<event>")]
+/// async fn webhook(event: OctoAppResult<WebHook<Event>>) -> OctoAppResult<OctoAppResponse> {
+///     match event?.into_inner() {
+///         Event::Issues(_) => Ok(OctoAppResponse::Accepted),
+///         _ => Ok(OctoAppResponse::Ignored("Not an issues event".to_string())),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OctoAppResponse {
+    /// The webhook was processed (`200 OK`)
+    Ok,
+    /// The webhook was accepted and will be processed later (`202 Accepted`)
+    Accepted,
+    /// The webhook was ignored, with the reason in the body (`200 OK`)
+    Ignored(String),
+}
+
+impl<'r> Responder<'r, 'r> for OctoAppResponse {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'r> {
+        match self {
+            OctoAppResponse::Ok => Status::Ok.respond_to(request),
+            OctoAppResponse::Accepted => Status::Accepted.respond_to(request),
+            OctoAppResponse::Ignored(reason) => (
+                Status::Ok,
+                Json(OctoAppApiError {
+                    status: "ignored".to_string(),
+                    message: Some(reason),
+                }),
+            )
+                .respond_to(request),
+        }
+    }
+}
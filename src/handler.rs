@@ -0,0 +1,47 @@
+//! # Webhook Handler
+//!
+//! The `WebhookHandler` trait is an alternative to the closure based
+//! `on_event` API for handlers which carry state (for example a database pool)
+//! or are split across multiple methods.
+//!
+//! ```no_run
+//! # #[cfg(feature = "smee")] {
+//! use octoapp::handler::WebhookHandler;
+//! use octoapp::prelude::*;
+//! use octoapp::smee::SmeeClient;
+//!
+//! struct Handler {
+//!     app_slug: String,
+//! }
+//!
+//! #[octoapp::handler::async_trait]
+//! impl WebhookHandler for Handler {
+//!     async fn handle(&self, webhook: WebHook<Event>) -> Result<(), OctoAppError> {
+//!         if webhook.triggered_by_app(&self.app_slug) {
+//!             return Ok(());
+//!         }
+//!         tracing::info!("Received event: {:?}", webhook.into_inner().kind());
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # async fn run(config: OctoAppConfig) -> Result<(), OctoAppError> {
+//! SmeeClient::new("https://smee.io/my-channel", config)
+//!     .with_handler(Handler { app_slug: "my-app".to_string() })
+//!     .run()
+//!     .await
+//! # }
+//! # }
+//! ```
+
+use crate::{events::Event, OctoAppError, WebHook};
+
+/// Re-export of `async_trait` to implement the `WebhookHandler` trait
+pub use async_trait::async_trait;
+
+/// Webhook Handler
+#[async_trait]
+pub trait WebhookHandler: Send + Sync {
+    /// Handle a verified webhook event
+    async fn handle(&self, webhook: WebHook<Event>) -> Result<(), OctoAppError>;
+}
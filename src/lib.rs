@@ -6,33 +6,73 @@
 #[cfg(feature = "octocrab")]
 extern crate octocrab;
 
+#[cfg(feature = "octocrab")]
+pub mod app;
+#[cfg(feature = "octocrab")]
+pub mod comments;
 pub mod config;
+pub mod dedup;
 pub mod error;
 #[cfg(feature = "octocrab")]
 pub mod events;
+#[cfg(feature = "octocrab")]
+pub mod handler;
+#[cfg(feature = "octocrab")]
+pub mod metrics;
+#[cfg(feature = "octocrab")]
+pub mod oauth;
+#[cfg(feature = "proxy")]
+mod proxy;
+#[cfg(feature = "publish")]
+pub mod publish;
+#[cfg(feature = "octocrab")]
+pub mod ratelimit;
+#[cfg(feature = "octocrab")]
+pub mod retry;
+#[cfg(feature = "testing")]
+pub mod testing;
 
+#[cfg(feature = "lambda")]
+pub mod ghlambda;
 #[cfg(feature = "rocket")]
 pub mod ghrocket;
+#[cfg(feature = "smee")]
+pub mod smee;
 
 pub use config::OctoAppConfig;
 pub use error::OctoAppError;
 #[cfg(feature = "octocrab")]
-pub use events::WebHook;
+pub use events::{parse_event, WebHook};
 
 #[cfg(feature = "rocket")]
-pub use crate::ghrocket::{OctoAppResult, OctoAppState};
+pub use crate::ghrocket::{OctoAppFairing, OctoAppResponse, OctoAppResult, OctoAppState};
 
 #[doc(hidden)]
 pub mod prelude {
     pub use crate::config::OctoAppConfig;
     pub use crate::error::OctoAppError;
     #[cfg(feature = "octocrab")]
-    pub use crate::events::{Event, WebHook};
+    pub use crate::events::{Enterprise, Event, EventKind, Sender, WebHook};
 
     // Re-export payloads
     #[cfg(feature = "octocrab")]
     pub use crate::events::payloads::*;
+    // Re-export payload helpers
+    #[cfg(feature = "octocrab")]
+    pub use crate::events::{
+        AlertEventExt, AlertState, CheckConclusion, CheckRunEventExt, CheckRunStatus,
+        CheckSuiteAction, CheckSuiteEventExt, CommitCommentAction, CommitCommentEventExt,
+        DeploymentState, DeploymentStatusEventExt, DiscussionAction, DiscussionCommentEventExt,
+        DiscussionEventExt, GollumEventExt, InstallationAction, InstallationEventExt,
+        IssuesEventExt, MemberAction, MemberEventExt, MembershipAction, MembershipEventExt,
+        MembershipScope, MergeGroupAction, MergeGroupEventExt, OrganizationAction,
+        OrganizationEventExt, PingEventExt, PullRequestEventExt, PullRequestReviewEventExt,
+        PushEventExt, ReleaseAction, ReleaseEventExt, RepositoryAction, RepositoryChangesExt,
+        RepositoryEventExt, ReviewState, SecretLocation, SecretLocationKind,
+        SecretScanningAlertLocationEventExt, Severity, StarAction, StarEventExt, TeamAction,
+        TeamAddEventExt, TeamEventExt, WatchAction, WatchEventExt, WikiPage, WikiPageAction,
+    };
 
     #[cfg(feature = "rocket")]
-    pub use crate::ghrocket::{OctoAppResult, OctoAppState};
+    pub use crate::ghrocket::{OctoAppFairing, OctoAppResponse, OctoAppResult, OctoAppState};
 }
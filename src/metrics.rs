@@ -0,0 +1,74 @@
+//! # Webhook Metrics
+//!
+//! The integrations report the outcome of each step of processing a webhook
+//! to an optional `Metrics` sink. Nothing is recorded unless a sink is set.
+//!
+//! ## Example (Prometheus)
+//!
+//! ```rust,ignore
+//! use octoapp::metrics::{Metrics, WebhookOutcome};
+//! use octoapp::prelude::*;
+//! use prometheus::IntCounterVec;
+//!
+//! struct PrometheusMetrics {
+//!     webhooks: IntCounterVec,
+//! }
+//!
+//! impl Metrics for PrometheusMetrics {
+//!     fn record(&self, kind: Option<EventKind>, outcome: WebhookOutcome) {
+//!         let kind = kind.map(|k| k.as_str()).unwrap_or("unknown");
+//!         self.webhooks
+//!             .with_label_values(&[kind, outcome.as_str()])
+//!             .inc();
+//!     }
+//! }
+//!
+//! let webhooks = prometheus::register_int_counter_vec!(
+//!     "octoapp_webhooks_total",
+//!     "Webhooks processed by outcome",
+//!     &["event", "outcome"]
+//! )?;
+//! let state = OctoAppState::new(config).metrics(PrometheusMetrics { webhooks });
+//! ```
+
+use crate::events::EventKind;
+
+/// The outcome of a step of processing a webhook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookOutcome {
+    /// The signature was verified
+    SignatureOk,
+    /// The signature was missing or invalid
+    SignatureFailed,
+    /// The payload was parsed
+    ParseOk,
+    /// The payload failed to parse
+    ParseFailed,
+    /// The handler completed successfully
+    HandlerOk,
+    /// The handler returned an error
+    HandlerError,
+}
+
+impl WebhookOutcome {
+    /// Get the name of the outcome (for metric labels)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookOutcome::SignatureOk => "signature_ok",
+            WebhookOutcome::SignatureFailed => "signature_failed",
+            WebhookOutcome::ParseOk => "parse_ok",
+            WebhookOutcome::ParseFailed => "parse_failed",
+            WebhookOutcome::HandlerOk => "handler_ok",
+            WebhookOutcome::HandlerError => "handler_error",
+        }
+    }
+}
+
+/// Sink for webhook processing metrics
+///
+/// The event kind is read from the `X-GitHub-Event` header and is `None` if
+/// the header is missing or unknown.
+pub trait Metrics: Send + Sync {
+    /// Record the outcome of a step of processing a webhook
+    fn record(&self, kind: Option<EventKind>, outcome: WebhookOutcome);
+}
@@ -0,0 +1,165 @@
+//! # OAuth (user-to-server) Authentication
+//!
+//! GitHub Apps can act on behalf of a user by completing the OAuth web flow.
+//! The authorization `code` GitHub redirects the user back with is exchanged
+//! for a user access token using the app's client id and secret.
+//!
+//! ```no_run
+//! # use octoapp::prelude::*;
+//! # async fn run(config: OctoAppConfig, code: &str) -> Result<(), OctoAppError> {
+//! let token = config.exchange_code(code).await?;
+//! println!("Token expires in: {:?}", token.expires_in);
+//!
+//! // Act as the user
+//! let client = config.octocrab_as_user(&token.access_token)?;
+//! let user = client.current().user().await?;
+//! println!("Authenticated as: {}", user.login);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{OctoAppConfig, OctoAppError};
+
+/// The GitHub host used for the OAuth web flow
+const GITHUB_OAUTH_URL: &str = "https://github.com";
+
+/// User access token
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UserToken {
+    /// The user access token
+    pub access_token: String,
+    /// The type of token (`bearer`)
+    pub token_type: String,
+    /// The scopes of the token (empty for GitHub Apps)
+    #[serde(default)]
+    pub scope: String,
+    /// The number of seconds until the access token expires
+    pub expires_in: Option<u64>,
+    /// The refresh token (if token expiration is enabled)
+    pub refresh_token: Option<String>,
+    /// The number of seconds until the refresh token expires
+    pub refresh_token_expires_in: Option<u64>,
+}
+
+/// OAuth access token request
+#[derive(serde::Serialize)]
+struct AccessTokenRequest<'a> {
+    client_id: &'a str,
+    client_secret: &'a str,
+    code: &'a str,
+}
+
+/// OAuth access token response (GitHub returns errors with a 200 status)
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum AccessTokenResponse {
+    Token(UserToken),
+    Error {
+        error: String,
+        error_description: Option<String>,
+    },
+}
+
+impl OctoAppConfig {
+    /// Exchange an OAuth authorization code for a user access token
+    ///
+    /// This requires the client id and client secret to be configured.
+    pub async fn exchange_code(&self, code: &str) -> Result<UserToken, OctoAppError> {
+        let client_id = self
+            .client_id()
+            .ok_or(OctoAppError::MissingField("Client ID".to_string()))?;
+        let client_secret = self
+            .client_secret()
+            .ok_or(OctoAppError::MissingField("Client Secret".to_string()))?;
+
+        #[cfg(feature = "proxy")]
+        if let Some(proxy) = self.proxy() {
+            let client = crate::proxy::octocrab_client(
+                self,
+                proxy,
+                GITHUB_OAUTH_URL,
+                octocrab::AuthState::None,
+                vec![
+                    (http::header::ACCEPT, "application/json".to_string()),
+                    (http::header::USER_AGENT, self.user_agent().to_string()),
+                ],
+            )?;
+            return exchange_code(&client, client_id, client_secret, code).await;
+        }
+
+        let client = octocrab::Octocrab::builder()
+            .base_uri(GITHUB_OAUTH_URL)?
+            .add_header(http::header::ACCEPT, "application/json".to_string())
+            .add_header(http::header::USER_AGENT, self.user_agent().to_string())
+            .set_connect_timeout(Some(self.connect_timeout()))
+            .set_read_timeout(Some(self.http_timeout()))
+            .set_write_timeout(Some(self.http_timeout()))
+            .build()?;
+
+        exchange_code(&client, client_id, client_secret, code).await
+    }
+
+    /// Create an Octocrab instance acting as the user (user access token)
+    ///
+    /// The token is the `access_token` from `exchange_code()`. This completes
+    /// the three ways a GitHub App authenticates: as the app (`octocrab_app()`),
+    /// as an installation (`octocrab_by_installation()`) and as a user.
+    pub fn octocrab_as_user(&self, user_token: &str) -> Result<octocrab::Octocrab, OctoAppError> {
+        if user_token.is_empty() {
+            return Err(OctoAppError::MissingField("User Access Token".to_string()));
+        }
+
+        #[cfg(feature = "proxy")]
+        if let Some(proxy) = self.proxy() {
+            return crate::proxy::octocrab_client(
+                self,
+                proxy,
+                self.base_uri(),
+                octocrab::AuthState::None,
+                vec![
+                    (
+                        http::header::AUTHORIZATION,
+                        format!("Bearer {}", user_token),
+                    ),
+                    (http::header::USER_AGENT, self.user_agent().to_string()),
+                ],
+            );
+        }
+
+        Ok(octocrab::Octocrab::builder()
+            .user_access_token(user_token.to_string())
+            .base_uri(self.base_uri())?
+            .add_header(http::header::USER_AGENT, self.user_agent().to_string())
+            .set_connect_timeout(Some(self.connect_timeout()))
+            .set_read_timeout(Some(self.http_timeout()))
+            .set_write_timeout(Some(self.http_timeout()))
+            .build()?)
+    }
+}
+
+/// Post the authorization code to the OAuth access token endpoint
+async fn exchange_code(
+    client: &octocrab::Octocrab,
+    client_id: &str,
+    client_secret: &str,
+    code: &str,
+) -> Result<UserToken, OctoAppError> {
+    let response: AccessTokenResponse = client
+        .post(
+            "/login/oauth/access_token",
+            Some(&AccessTokenRequest {
+                client_id,
+                client_secret,
+                code,
+            }),
+        )
+        .await?;
+
+    match response {
+        AccessTokenResponse::Token(token) => Ok(token),
+        AccessTokenResponse::Error {
+            error,
+            error_description,
+        } => Err(OctoAppError::OAuthError(error_description.unwrap_or(error))),
+    }
+}
@@ -0,0 +1,96 @@
+//! # Event Publishing
+//!
+//! An `EventSink` receives every verified webhook so it can be forwarded to a
+//! queue, decoupling ingestion from processing. The body is the exact payload
+//! GitHub sent (the bytes the signature was verified against).
+//!
+//! A NATS implementation is available with the `nats` feature.
+//!
+//! ```no_run
+//! # #[cfg(all(feature = "nats", feature = "rocket"))] {
+//! use octoapp::prelude::*;
+//! use octoapp::publish::NatsSink;
+//!
+//! # async fn run(config: OctoAppConfig) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = async_nats::connect("nats://localhost:4222").await?;
+//! // Events are published to `github.{event}`, for example `github.issues`
+//! let state = OctoAppState::new(config).sink(NatsSink::new(client));
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+
+use crate::{events::EventKind, OctoAppError};
+
+/// Re-export of `async_trait` to implement the `EventSink` trait
+pub use async_trait::async_trait;
+
+/// Sink for verified webhook events
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    /// Publish a verified webhook event
+    ///
+    /// The delivery ID is the `X-GitHub-Delivery` header (if present) which
+    /// can be used to deduplicate redeliveries downstream.
+    async fn publish(
+        &self,
+        kind: EventKind,
+        body: &[u8],
+        delivery_id: Option<&str>,
+    ) -> Result<(), OctoAppError>;
+}
+
+/// NATS Event Sink
+///
+/// Publishes each event to a subject named after the event kind
+/// (`{prefix}.{event}`, the prefix defaults to `github`). The delivery ID is
+/// sent as the `Nats-Msg-Id` header which JetStream uses for deduplication.
+#[cfg(feature = "nats")]
+#[derive(Debug, Clone)]
+pub struct NatsSink {
+    client: async_nats::Client,
+    prefix: String,
+}
+
+#[cfg(feature = "nats")]
+impl NatsSink {
+    /// Create a new NatsSink using the connected client
+    pub fn new(client: async_nats::Client) -> Self {
+        Self {
+            client,
+            prefix: "github".to_string(),
+        }
+    }
+
+    /// Set the subject prefix (default: `github`)
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Get the subject for the event kind
+    pub fn subject(&self, kind: EventKind) -> String {
+        format!("{}.{}", self.prefix, kind.as_str())
+    }
+}
+
+#[cfg(feature = "nats")]
+#[async_trait]
+impl EventSink for NatsSink {
+    async fn publish(
+        &self,
+        kind: EventKind,
+        body: &[u8],
+        delivery_id: Option<&str>,
+    ) -> Result<(), OctoAppError> {
+        let mut headers = async_nats::HeaderMap::new();
+        if let Some(delivery_id) = delivery_id {
+            headers.insert(async_nats::header::NATS_MESSAGE_ID, delivery_id);
+        }
+
+        self.client
+            .publish_with_headers(self.subject(kind), headers, body.to_vec().into())
+            .await
+            .map_err(|e| OctoAppError::PublishError(e.to_string()))
+    }
+}
@@ -0,0 +1,91 @@
+//! # Rate Limit Module
+//!
+//! GitHub limits the number of API requests an app (or installation) can make
+//! per hour. High volume apps can check the remaining quota and back off
+//! before hitting the primary rate limit.
+//!
+//! ```no_run
+//! # async fn run(config: octoapp::OctoAppConfig) -> Result<(), octoapp::OctoAppError> {
+//! let rate_limit = config.rate_limit_status().await?;
+//! if rate_limit.should_throttle(100) {
+//!     tokio::time::sleep(rate_limit.reset_in()).await;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Checking the rate limit (`GET /rate_limit`) doesn't count against it.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{OctoAppConfig, OctoAppError};
+
+/// The core (REST API) rate limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RateLimit {
+    /// The maximum number of requests per hour
+    pub limit: u64,
+    /// The number of requests remaining in the current window
+    pub remaining: u64,
+    /// The number of requests made in the current window
+    pub used: u64,
+    /// The time the current window resets (UTC epoch seconds)
+    pub reset: u64,
+}
+
+impl RateLimit {
+    /// Check if the remaining requests are below the threshold
+    pub fn should_throttle(&self, threshold: u64) -> bool {
+        self.remaining < threshold
+    }
+
+    /// Get the time until the current window resets
+    pub fn reset_in(&self) -> Duration {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Duration::from_secs(self.reset.saturating_sub(now))
+    }
+}
+
+/// Response of `GET /rate_limit`
+#[derive(serde::Deserialize)]
+struct RateLimitResponse {
+    rate: RateLimit,
+}
+
+impl OctoAppConfig {
+    /// Get the core rate limit status (`GET /rate_limit`)
+    ///
+    /// This uses the same client as `octocrab()`, so it is the rate limit of
+    /// the first installation once installed, otherwise the app's.
+    pub async fn rate_limit_status(&self) -> Result<RateLimit, OctoAppError> {
+        let client = self.octocrab()?;
+        let response: RateLimitResponse = client.get("/rate_limit", None::<&()>).await?;
+        Ok(response.rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit() {
+        let response: RateLimitResponse = serde_json::from_str(
+            r#"{
+                "resources": {"core": {"limit": 5000, "remaining": 42, "reset": 1727784000, "used": 4958}},
+                "rate": {"limit": 5000, "remaining": 42, "reset": 1727784000, "used": 4958}
+            }"#,
+        )
+        .unwrap();
+        let rate_limit = response.rate;
+
+        assert_eq!(rate_limit.limit, 5000);
+        assert!(rate_limit.should_throttle(100));
+        assert!(!rate_limit.should_throttle(42));
+        // The reset time is in the past
+        assert_eq!(rate_limit.reset_in(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,123 @@
+//! # Retry Module
+//!
+//! GitHub occasionally returns server errors (5xx) or secondary rate limits
+//! for otherwise valid requests. The `RetryPolicy` retries these transient
+//! errors (see `OctoAppError::is_retryable`) with an exponential backoff.
+//!
+//! ```no_run
+//! # async fn run(config: octoapp::OctoAppConfig) -> Result<(), octoapp::OctoAppError> {
+//! let client = config.octocrab_by_installation(123456).await?;
+//!
+//! let repo = config
+//!     .with_retry(|| async { Ok(client.repos("42ByteLabs", "octoapp").get().await?) })
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Note that Octocrab doesn't expose the response headers on errors so the
+//! `Retry-After` header can't be read, secondary rate limits are backed off
+//! using the same exponential delay.
+
+use std::{future::Future, time::Duration};
+
+use crate::OctoAppError;
+
+/// The default number of attempts (including the first)
+const RETRY_MAX_ATTEMPTS: u32 = 3;
+/// The default delay before the first retry
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+/// The maximum delay between attempts
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Retry Policy for transient GitHub API errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts (including the first)
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled for each following retry
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Create a new RetryPolicy
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+        }
+    }
+
+    /// Create a RetryPolicy that never retries
+    pub fn none() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+
+    /// Get the delay before the given retry (starting at 1)
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(RETRY_MAX_DELAY)
+    }
+
+    /// Run the function, retrying it while it returns a retryable error
+    pub async fn retry<T, F, Fut>(&self, mut f: F) -> Result<T, OctoAppError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, OctoAppError>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match f().await {
+                Err(e) if e.is_retryable() && attempt < self.max_attempts => {
+                    let delay = self.delay(attempt);
+                    tracing::warn!(
+                        "Retrying in {:?} (attempt {}/{}): {}",
+                        delay,
+                        attempt + 1,
+                        self.max_attempts,
+                        e
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(RETRY_MAX_ATTEMPTS, RETRY_BASE_DELAY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(500));
+        assert_eq!(policy.delay(1), Duration::from_millis(500));
+        assert_eq!(policy.delay(2), Duration::from_secs(1));
+        assert_eq!(policy.delay(3), Duration::from_secs(2));
+        assert_eq!(policy.delay(100), RETRY_MAX_DELAY);
+    }
+
+    #[tokio::test]
+    async fn test_retry_stops_on_permanent_error() {
+        let policy = RetryPolicy::new(3, Duration::ZERO);
+        let mut calls = 0;
+        let result: Result<(), _> = policy
+            .retry(|| {
+                calls += 1;
+                async { Err(OctoAppError::MissingField("AppID".to_string())) }
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+}
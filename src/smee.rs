@@ -0,0 +1,319 @@
+//! # Smee Module
+//!
+//! This module contains a [smee.io](https://smee.io) client for local development.
+//!
+//! Smee forwards the webhooks GitHub sends to a public channel URL using
+//! Server-Sent Events (SSE). The `SmeeClient` connects to the channel,
+//! reconstructs each webhook request (headers + body) and runs it through the
+//! same signature verification and parsing as the other integrations.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! # #[cfg(feature = "smee")] {
+//! use octoapp::prelude::*;
+//! use octoapp::smee::SmeeClient;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), OctoAppError> {
+//!     let config = OctoAppConfig::init().build()?;
+//!
+//!     SmeeClient::new("https://smee.io/my-channel", config)
+//!         .on_event(|event: WebHook<Event>| async move {
+//!             tracing::info!("Received event for installation: {}", event.installation());
+//!             Ok(())
+//!         })
+//!         .run()
+//!         .await
+//! }
+//! # }
+//! ```
+//!
+
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use futures_util::StreamExt;
+use tracing::Instrument;
+
+use crate::{
+    events::{Event, EventKind},
+    handler::WebhookHandler,
+    metrics::{Metrics, WebhookOutcome},
+    OctoAppConfig, OctoAppError, WebHook,
+};
+
+/// Boxed event handler used by the SmeeClient
+type EventHandler = Arc<
+    dyn Fn(WebHook<Event>) -> Pin<Box<dyn Future<Output = Result<(), OctoAppError>> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// A webhook request forwarded by smee.io
+///
+/// Smee flattens the original request headers (lowercased) and the body into
+/// a single JSON object.
+#[derive(serde::Deserialize)]
+struct SmeeMessage {
+    #[serde(rename = "x-github-event")]
+    event: Option<String>,
+    #[serde(rename = "x-github-delivery")]
+    delivery: Option<String>,
+    #[serde(rename = "x-hub-signature-256")]
+    signature: Option<String>,
+    /// The body is kept raw so the signature is verified against the
+    /// forwarded bytes and not a re-serialized copy.
+    body: Option<Box<serde_json::value::RawValue>>,
+}
+
+/// Smee.io webhook proxy client
+///
+/// Connects to a smee.io channel and processes the forwarded webhooks.
+pub struct SmeeClient {
+    url: String,
+    config: OctoAppConfig,
+    handler: Option<EventHandler>,
+    metrics: Option<Arc<dyn Metrics>>,
+    only: Option<Vec<EventKind>>,
+    require_installation: bool,
+    trace_payloads: bool,
+    #[cfg(feature = "publish")]
+    sink: Option<Arc<dyn crate::publish::EventSink>>,
+}
+
+impl SmeeClient {
+    /// Create a new SmeeClient for the given channel URL
+    pub fn new(url: impl Into<String>, config: OctoAppConfig) -> Self {
+        Self {
+            url: url.into(),
+            config,
+            handler: None,
+            metrics: None,
+            only: None,
+            require_installation: false,
+            trace_payloads: false,
+            #[cfg(feature = "publish")]
+            sink: None,
+        }
+    }
+
+    /// Set the handler called for each verified webhook event
+    pub fn on_event<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(WebHook<Event>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), OctoAppError>> + Send + 'static,
+    {
+        self.handler = Some(Arc::new(move |event| Box::pin(handler(event))));
+        self
+    }
+
+    /// Set the handler called for each verified webhook event
+    ///
+    /// This is the same as `on_event` but uses a `WebhookHandler` which can
+    /// carry state.
+    pub fn with_handler(self, handler: impl WebhookHandler + 'static) -> Self {
+        let handler = Arc::new(handler);
+        self.on_event(move |event| {
+            let handler = handler.clone();
+            async move { handler.handle(event).await }
+        })
+    }
+
+    /// Set the sink for webhook metrics
+    pub fn metrics(mut self, metrics: impl Metrics + 'static) -> Self {
+        self.metrics = Some(Arc::new(metrics));
+        self
+    }
+
+    /// Only process the given event kinds
+    ///
+    /// Other events (by the `X-GitHub-Event` header) are skipped before the
+    /// payload is parsed.
+    pub fn only(mut self, kinds: &[EventKind]) -> Self {
+        self.only = Some(kinds.to_vec());
+        self
+    }
+
+    /// Skip payloads without an installation (default: false)
+    pub fn require_installation(mut self, require_installation: bool) -> Self {
+        self.require_installation = require_installation;
+        self
+    }
+
+    /// Record the high signal payload fields as tracing fields (default: false)
+    ///
+    /// The handler runs in a `webhook` span with the event, delivery,
+    /// repository, action, sender and issue / pull request number. The body
+    /// and secrets are never recorded.
+    pub fn trace_payloads(mut self, trace_payloads: bool) -> Self {
+        self.trace_payloads = trace_payloads;
+        self
+    }
+
+    /// Set the sink to forward every verified event to
+    #[cfg(feature = "publish")]
+    pub fn sink(mut self, sink: impl crate::publish::EventSink + 'static) -> Self {
+        self.sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Connect to the smee.io channel and process events until the stream closes
+    ///
+    /// Errors for individual events are logged and do not stop the client.
+    pub async fn run(self) -> Result<(), OctoAppError> {
+        tracing::info!("Connecting to smee channel: {}", self.url);
+
+        let client = reqwest::Client::builder();
+        #[cfg(feature = "proxy")]
+        let client = match self.config.proxy() {
+            Some(proxy) => client.proxy(reqwest::Proxy::all(proxy.to_string())?),
+            None => client,
+        };
+
+        let response = client
+            .build()?
+            .get(&self.url)
+            .header("Accept", "text/event-stream")
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let mut stream = response.bytes_stream();
+        let mut buffer: Vec<u8> = Vec::new();
+
+        while let Some(chunk) = stream.next().await {
+            buffer.extend_from_slice(&chunk?);
+
+            // SSE messages are separated by a blank line
+            while let Some(pos) = buffer.windows(2).position(|w| w == b"\n\n") {
+                let message: Vec<u8> = buffer.drain(..pos + 2).collect();
+                let message = String::from_utf8_lossy(&message);
+
+                let data: Vec<&str> = message
+                    .lines()
+                    .filter_map(|line| line.strip_prefix("data:"))
+                    .map(|line| line.trim_start())
+                    .collect();
+                if data.is_empty() {
+                    continue;
+                }
+
+                if let Err(e) = self.handle_message(&data.join("\n")).await {
+                    tracing::error!("Failed to process smee message: {}", e);
+                }
+            }
+        }
+
+        tracing::info!("Smee channel closed: {}", self.url);
+        Ok(())
+    }
+
+    /// Verify, parse and dispatch a single smee message
+    async fn handle_message(&self, data: &str) -> Result<(), OctoAppError> {
+        let message: SmeeMessage = serde_json::from_str(data)?;
+
+        // Smee also sends `ready` and `ping` messages without a webhook body
+        let Some(body) = message.body else {
+            return Ok(());
+        };
+        tracing::debug!(
+            "Smee delivery {:?} for event {:?}",
+            message.delivery,
+            message.event
+        );
+
+        let kind: Option<EventKind> = message.event.as_deref().and_then(|e| e.parse().ok());
+        if let Some(only) = &self.only {
+            if !kind.is_some_and(|kind| only.contains(&kind)) {
+                tracing::debug!("Ignoring event: {:?}", message.event);
+                return Ok(());
+            }
+        }
+        let record = |outcome: WebhookOutcome| {
+            if let Some(metrics) = &self.metrics {
+                metrics.record(kind, outcome);
+            }
+        };
+
+        // Validate the request signature
+        let verified = match message.signature {
+            Some(signature) => self
+                .config
+                .webhook_signature_verification(body.get().as_bytes(), signature),
+            None => self.config.insecure_skip_signature_verification(),
+        };
+        if !verified {
+            record(WebhookOutcome::SignatureFailed);
+            return Err(OctoAppError::SignatureError(
+                "Missing or invalid X-Hub-Signature-256 header".to_string(),
+            ));
+        }
+        record(WebhookOutcome::SignatureOk);
+        self.config
+            .check_installation_allowed(crate::events::extract_installation_id(body.get()))?;
+
+        #[cfg(feature = "publish")]
+        if let (Some(sink), Some(kind)) = (&self.sink, kind) {
+            sink.publish(kind, body.get().as_bytes(), message.delivery.as_deref())
+                .await?;
+        }
+
+        if kind == Some(EventKind::Ping) {
+            crate::events::log_ping(body.get());
+        }
+
+        // Parse into the variant of the event header (as `process_webhook`
+        // does), the untagged parsing is only used if the header is unknown
+        let parsed = match kind {
+            Some(kind) => WebHook::<Event>::from_event_str(kind, body.get()),
+            None => WebHook::<Event>::from_json_str(body.get()),
+        };
+        let event = match parsed {
+            Ok(event) => {
+                tracing::debug!(
+                    "Parsed {} webhook as Event::{}",
+                    message.event.as_deref().unwrap_or("unknown"),
+                    event.0.variant_name()
+                );
+                record(WebhookOutcome::ParseOk);
+                event
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to parse {} webhook (delivery {}): {}",
+                    message.event.as_deref().unwrap_or("unknown"),
+                    message.delivery.as_deref().unwrap_or("unknown"),
+                    e
+                );
+                record(WebhookOutcome::ParseFailed);
+                return Err(e);
+            }
+        };
+
+        if self.require_installation && event.installation() == 0 {
+            return Err(OctoAppError::MissingInstallation);
+        }
+
+        let span = if self.trace_payloads {
+            crate::events::webhook_span(
+                message.event.as_deref(),
+                message.delivery.as_deref(),
+                body.get(),
+            )
+        } else {
+            tracing::Span::none()
+        };
+        span.in_scope(|| tracing::debug!("Dispatching webhook"));
+
+        if let Some(handler) = &self.handler {
+            match handler(event).instrument(span).await {
+                Ok(()) => record(WebhookOutcome::HandlerOk),
+                Err(e) => {
+                    record(WebhookOutcome::HandlerError);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}